@@ -0,0 +1,41 @@
+//! Run with
+//!
+//! ```not_rust
+//! cargo run --example dcp-guard API_KEY API_SECRET
+//! ```
+
+use bybit::v5::{
+    BASE_URL_API_TESTNET, BASE_URL_STREAM_TESTNET, Client, ClientConfig, DcpEvent, DcpFunction,
+    DcpGuard, Path, SensitiveString,
+};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args: Vec<_> = std::env::args().collect();
+    let api_key = args.get(1).expect("API_KEY is required");
+    let api_secret = args.get(2).expect("API_SECRET is required");
+
+    let cfg = ClientConfig {
+        base_url: BASE_URL_API_TESTNET.to_owned(),
+        api_key: Some(SensitiveString::from(api_key.to_owned())),
+        api_secret: Some(SensitiveString::from(api_secret.to_owned())),
+        recv_window: 5000, // Milliseconds.
+        referer: None,
+    };
+    let client = Client::new(cfg);
+
+    let url = format!("{}{}", BASE_URL_STREAM_TESTNET, Path::Private);
+    let time_window = 10; // Seconds.
+    let mut guard = DcpGuard::start(&client, &url, DcpFunction::Future, time_window).await?;
+
+    while let Some(event) = guard.recv().await {
+        match event {
+            DcpEvent::Status(status) => println!("{status:#?}"),
+            DcpEvent::Disconnected => println!("DCP private stream disconnected, reconnecting"),
+            DcpEvent::Reconnected => println!("DCP private stream reconnected"),
+            DcpEvent::ReconnectFailed(e) => println!("DCP private stream reconnect failed: {e}"),
+        }
+    }
+
+    Ok(())
+}
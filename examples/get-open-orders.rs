@@ -4,11 +4,8 @@
 //! cargo run --example get-open-orders API_KEY API_SECRET
 //! ```
 
-use tokio;
-
 use bybit::v5::{
-    BASE_URL_API_DEMO_TRADING, Category, Client, ClientConfig, GetOpenClosedOrdersParams,
-    SensitiveString,
+    BASE_URL_API_DEMO_TRADING, Client, ClientConfig, GetOpenClosedOrdersParams, SensitiveString,
 };
 
 #[tokio::main]
@@ -28,18 +25,7 @@ async fn main() -> anyhow::Result<()> {
     };
     let client = Client::new(cfg);
 
-    let params = GetOpenClosedOrdersParams {
-        category: Category::Linear,
-        symbol: Some(String::from("BTCUSDT")), // For linear is required.
-        base_coin: None,
-        settle_coin: None,
-        order_id: None,
-        order_link_id: None,
-        open_only: None,
-        order_filter: None,
-        limit: Some(10),
-        cursor: None,
-    };
+    let params = GetOpenClosedOrdersParams::linear("BTCUSDT").limit(10);
     let response = client.get_open_closed_orders(params).await?;
     println!("{response:#?}");
 
@@ -4,11 +4,8 @@
 //! cargo run --example get-position-info API_KEY API_SECRET
 //! ```
 
-use tokio;
-
 use bybit::v5::{
-    BASE_URL_API_DEMO_TRADING, Category, Client, ClientConfig, GetPositionInfoParams,
-    SensitiveString,
+    BASE_URL_API_DEMO_TRADING, Client, ClientConfig, GetPositionInfoParams, SensitiveString,
 };
 
 #[tokio::main]
@@ -28,14 +25,7 @@ async fn main() -> anyhow::Result<()> {
     };
     let client = Client::new(cfg);
 
-    let params = GetPositionInfoParams {
-        category: Category::Linear,
-        symbol: Some(String::from("BTCUSDT")),
-        base_coin: None,
-        settle_coin: None,
-        limit: Some(10),
-        cursor: None,
-    };
+    let params = GetPositionInfoParams::linear("BTCUSDT").limit(10);
     let response = client.get_position_info(params).await?;
     println!("{response:#?}");
 
@@ -4,9 +4,7 @@
 //! cargo run --example instruments-info
 //! ```
 
-use tokio;
-
-use bybit::v5::{BASE_URL_API_MAINNET_1, Category, Client, ClientConfig, GetInstrumentsInfoParams};
+use bybit::v5::{BASE_URL_API_MAINNET_1, Client, ClientConfig, GetInstrumentsInfoParams};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -18,14 +16,7 @@ async fn main() -> anyhow::Result<()> {
         referer: None,
     };
     let client = Client::new(cfg);
-    let params = GetInstrumentsInfoParams {
-        category: Category::Linear,
-        symbol: Some(String::from("BTCUSDT")),
-        status: None,
-        base_coin: None,
-        limit: None,
-        cursor: None,
-    };
+    let params = GetInstrumentsInfoParams::linear("BTCUSDT");
     let response = client.get_instruments_info(params).await?;
     println!("{response:#?}");
 
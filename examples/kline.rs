@@ -4,8 +4,6 @@
 //! cargo run --example kline
 //! ```
 
-use tokio;
-
 use bybit::v5::{
     BASE_URL_API_MAINNET_1, Category, Client, ClientConfig, GetKLinesParams, Interval,
 };
@@ -20,14 +18,7 @@ async fn main() -> anyhow::Result<()> {
         referer: None,
     };
     let client = Client::new(cfg);
-    let params = GetKLinesParams {
-        category: Category::Linear,
-        symbol: String::from("BTCUSDT"),
-        interval: Interval::Minute1,
-        start: None,
-        end: None,
-        limit: Some(2),
-    };
+    let params = GetKLinesParams::new(Category::Linear, "BTCUSDT", Interval::Minute1).limit(2);
     let response = client.get_kline(params).await?;
     println!("{response:#?}");
 
@@ -4,9 +4,7 @@
 //! cargo run --example recent-trading-history
 //! ```
 
-use tokio;
-
-use bybit::v5::{BASE_URL_API_MAINNET_1, Category, Client, ClientConfig, GetTradesParams};
+use bybit::v5::{BASE_URL_API_MAINNET_1, Client, ClientConfig, GetTradesParams};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -18,13 +16,7 @@ async fn main() -> anyhow::Result<()> {
         referer: None,
     };
     let client = Client::new(cfg);
-    let params = GetTradesParams {
-        category: Category::Linear,
-        symbol: Some(String::from("BTCUSDT")),
-        base_coin: None,
-        option_type: None,
-        limit: Some(2),
-    };
+    let params = GetTradesParams::linear("BTCUSDT").limit(2);
     let response = client.get_public_recent_trading_history(params).await?;
     println!("{response:#?}");
 
@@ -4,8 +4,6 @@
 //! cargo run --example server-time
 //! ```
 
-use tokio;
-
 use bybit::v5::{BASE_URL_API_MAINNET_1, Client, ClientConfig};
 
 #[tokio::main]
@@ -4,8 +4,6 @@
 //! cargo run --example stream-all-liquidation
 //! ```
 
-use tokio;
-
 use bybit::v5::{
     BASE_URL_STREAM_MAINNET_1, DEFAULT_PING_INTERVAL, IncomingMessage, OutgoingMessage, Path,
     Topic, stream,
@@ -4,9 +4,7 @@
 //! cargo run --example ticker
 //! ```
 
-use tokio;
-
-use bybit::v5::{BASE_URL_API_MAINNET_1, Category, Client, ClientConfig, GetTickersParams};
+use bybit::v5::{BASE_URL_API_MAINNET_1, Client, ClientConfig, GetTickersParams};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -18,12 +16,7 @@ async fn main() -> anyhow::Result<()> {
         referer: None,
     };
     let client = Client::new(cfg);
-    let params = GetTickersParams {
-        category: Category::Linear,
-        symbol: Some(String::from("BTCUSDT")),
-        base_coin: None, // If category=option, symbol or baseCoin must be passed.
-        exp_date: None,
-    };
+    let params = GetTickersParams::linear("BTCUSDT");
     let response = client.get_tickers(params).await?;
     println!("{response:#?}");
 
@@ -1,18 +1,28 @@
+use std::collections::HashMap;
+
 use rust_decimal::{Decimal, serde::str_option::deserialize as option_decimal};
 use serde::{Deserialize, Serialize};
 use serde_aux::prelude::{
     deserialize_number_from_string as number,
     deserialize_option_number_from_string as option_number,
 };
+use uuid::Uuid;
 
 use crate::v5::{
-    AdlRankIndicator, CancelType, ContractType, CopyTrading, CreateType, CurAuctionPhase,
-    OcoTriggerBy, OrderStatus, OrderType, PlaceType, PositionIdx, PositionStatus, RejectReason,
-    Side, SmpType, Status, StopOrderType, TimeInForce, TpslMode, TradeMode, TriggerBy,
-    TriggerDirection,
+    AccountType, AdlRankIndicator, AnnouncementType, CancelType, ContractType, ConvertAccountType,
+    ConvertSide, ConvertStatus, CopyTrading, CreateType, CurAuctionPhase, DataRecordingPeriod,
+    DcpProduct, DemoAdjustType, DepositStatus, ExecType, IntervalTime, Locale, LtOrderStatus,
+    LtOrderType, LtStatus, MarginMode, OcoTriggerBy, OptionPeriod, OrderStatus, OrderType,
+    PlaceType, PositionIdx, PositionMode, PositionStatus, RejectReason, Side, SmpType, Status,
+    StopOrderType, SubMemberStatus, SubMemberType, SystemState, TimeInForce, TpslMode, TradeMode,
+    TransferStatus, TriggerBy, TriggerDirection, Type, UnifiedMarginStatus, UnifiedUpdateStatus,
+    VipLevel, WithdrawAccountType, WithdrawStatus, WithdrawType,
+    crypto::{SensitiveString, timestamp},
     enums::{Category, Interval},
     serde::{
-        empty_string_as_none, int_to_bool, invalid_as_none, string_to_bool, string_to_option_bool,
+        bool_to_int, bool_to_on_off, bool_to_string, empty_string_as_none, int_to_bool,
+        invalid_as_none, on_off_to_bool, option_bool_to_int, option_on_off_to_bool, string_to_bool,
+        string_to_option_bool,
     },
 };
 
@@ -51,6 +61,17 @@ pub struct CursorPagination<T> {
     pub list: Vec<T>,
 }
 
+/// A list result. Category and cursor are set only by the endpoints that return them.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct List<T> {
+    #[serde(default, deserialize_with = "invalid_as_none")]
+    pub category: Option<Category>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub next_page_cursor: Option<String>,
+    pub list: Vec<T>,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct Headers {
     pub ret_code: Option<i32>,
@@ -74,6 +95,7 @@ impl Headers {
 pub struct RetExtInfo {}
 
 #[derive(Serialize)]
+#[non_exhaustive]
 pub struct GetKLinesParams {
     pub category: Category,
     pub symbol: String,
@@ -83,6 +105,34 @@ pub struct GetKLinesParams {
     pub limit: Option<u64>,
 }
 
+impl GetKLinesParams {
+    pub fn new(category: Category, symbol: impl Into<String>, interval: Interval) -> Self {
+        Self {
+            category,
+            symbol: symbol.into(),
+            interval,
+            start: None,
+            end: None,
+            limit: None,
+        }
+    }
+
+    pub fn start(mut self, start: Timestamp) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    pub fn end(mut self, end: Timestamp) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(tag = "category")]
 pub enum KLine {
@@ -122,6 +172,8 @@ pub struct KLineRow {
 }
 
 #[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct GetTickersParams {
     pub category: Category,
     pub symbol: Option<String>,
@@ -129,6 +181,48 @@ pub struct GetTickersParams {
     pub exp_date: Option<String>,
 }
 
+impl GetTickersParams {
+    pub fn new(category: Category) -> Self {
+        Self {
+            category,
+            symbol: None,
+            base_coin: None,
+            exp_date: None,
+        }
+    }
+
+    pub fn spot(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Spot).symbol(symbol)
+    }
+
+    pub fn linear(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Linear).symbol(symbol)
+    }
+
+    pub fn inverse(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Inverse).symbol(symbol)
+    }
+
+    pub fn option(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Option).symbol(symbol)
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn base_coin(mut self, base_coin: impl Into<String>) -> Self {
+        self.base_coin = Some(base_coin.into());
+        self
+    }
+
+    pub fn exp_date(mut self, exp_date: impl Into<String>) -> Self {
+        self.exp_date = Some(exp_date.into());
+        self
+    }
+}
+
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(tag = "category")]
 pub enum Ticker {
@@ -302,6 +396,71 @@ pub struct SpotTicker {
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetFundingRateHistoryParams {
+    /// Product type. linear, inverse
+    pub category: Category,
+    /// Symbol name, uppercase only
+    pub symbol: String,
+    /// The start timestamp (ms)
+    /// Passing only startTime returns an error. Passing only endTime returns 200 records up till endTime
+    pub start_time: Option<Timestamp>,
+    /// The end timestamp (ms)
+    pub end_time: Option<Timestamp>,
+    /// Limit for data size per page. [1, 200]. Default: 200
+    pub limit: Option<u64>,
+}
+
+impl GetFundingRateHistoryParams {
+    pub fn new(category: Category, symbol: impl Into<String>) -> Self {
+        Self {
+            category,
+            symbol: symbol.into(),
+            start_time: None,
+            end_time: None,
+            limit: None,
+        }
+    }
+
+    pub fn linear(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Linear, symbol)
+    }
+
+    pub fn inverse(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Inverse, symbol)
+    }
+
+    pub fn start_time(mut self, start_time: Timestamp) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: Timestamp) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    /// Symbol name
+    pub symbol: String,
+    /// Funding rate
+    pub funding_rate: Decimal,
+    /// Funding rate timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub funding_rate_timestamp: Timestamp,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct GetTradesParams {
     pub category: Category,
     /// required for spot/linear/inverse
@@ -310,13 +469,61 @@ pub struct GetTradesParams {
     /// Apply to option only
     /// If the field is not passed, return BTC data by default
     pub base_coin: Option<String>,
-    /// optionType	false	string	Option type. Call or Put. Apply to option only
+    /// optionType false string Option type. Call or Put. Apply to option only
     pub option_type: Option<u64>,
     /// spot: [1,60], default: 60
     /// others: [1,1000], default: 500
     pub limit: Option<u64>,
 }
 
+impl GetTradesParams {
+    pub fn new(category: Category) -> Self {
+        Self {
+            category,
+            symbol: None,
+            base_coin: None,
+            option_type: None,
+            limit: None,
+        }
+    }
+
+    pub fn spot(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Spot).symbol(symbol)
+    }
+
+    pub fn linear(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Linear).symbol(symbol)
+    }
+
+    pub fn inverse(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Inverse).symbol(symbol)
+    }
+
+    pub fn option(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Option).symbol(symbol)
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn base_coin(mut self, base_coin: impl Into<String>) -> Self {
+        self.base_coin = Some(base_coin.into());
+        self
+    }
+
+    pub fn option_type(mut self, option_type: u64) -> Self {
+        self.option_type = Some(option_type);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(tag = "category")]
 pub enum Trade {
@@ -346,7 +553,7 @@ pub struct InverseLinearSpotTrade {
     /// Trade time (ms)
     #[serde(deserialize_with = "number")]
     pub time: Timestamp,
-    /// boolean	Whether the trade is block trade
+    /// boolean Whether the trade is block trade
     pub is_block_trade: bool,
     /// Whether the trade is RPI trade
     #[serde(rename = "isRPITrade")]
@@ -369,7 +576,7 @@ pub struct OptionTrade {
     /// Trade time (ms)
     #[serde(deserialize_with = "number")]
     pub time: Timestamp,
-    /// boolean	Whether the trade is block trade
+    /// boolean Whether the trade is block trade
     pub is_block_trade: bool,
     /// Whether the trade is RPI trade
     #[serde(rename = "isRPITrade")]
@@ -399,6 +606,8 @@ pub struct ServerTime {
     pub time_nano: u64,
 }
 #[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct GetInstrumentsInfoParams {
     pub category: Category,
     pub symbol: Option<String>,
@@ -408,6 +617,60 @@ pub struct GetInstrumentsInfoParams {
     pub cursor: Option<String>,
 }
 
+impl GetInstrumentsInfoParams {
+    pub fn new(category: Category) -> Self {
+        Self {
+            category,
+            symbol: None,
+            status: None,
+            base_coin: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+
+    pub fn spot(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Spot).symbol(symbol)
+    }
+
+    pub fn linear(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Linear).symbol(symbol)
+    }
+
+    pub fn inverse(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Inverse).symbol(symbol)
+    }
+
+    pub fn option(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Option).symbol(symbol)
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn base_coin(mut self, base_coin: impl Into<String>) -> Self {
+        self.base_coin = Some(base_coin.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(tag = "category")]
 pub enum InstrumentsInfo {
@@ -466,36 +729,82 @@ pub struct InverseLinearInstrumentsInfo {
     pub pre_listing_info: Option<PreListingInfo>,
 }
 
+impl InverseLinearInstrumentsInfo {
+    /// Funding interval in milliseconds. Zero for instruments without funding (futures).
+    pub fn funding_interval_ms(&self) -> u64 {
+        self.funding_interval.max(0) as u64 * 60 * 1000
+    }
+
+    /// Predict the next `count` funding timestamps (ms) strictly after `now` (ms).
+    /// Funding is settled at multiples of the funding interval counted from 00:00 UTC.
+    pub fn next_funding_times(&self, now: Timestamp, count: usize) -> Vec<Timestamp> {
+        let interval = self.funding_interval_ms();
+        if interval == 0 {
+            return vec![];
+        }
+
+        let next = (now / interval + 1) * interval;
+        (0..count as u64).map(|i| next + i * interval).collect()
+    }
+}
+
+impl InstrumentsInfo {
+    /// Cursor of the next page, `None` on the last page.
+    pub fn next_page_cursor(&self) -> Option<&str> {
+        let cursor = match self {
+            Self::Inverse {
+                next_page_cursor, ..
+            }
+            | Self::Linear {
+                next_page_cursor, ..
+            }
+            | Self::Option {
+                next_page_cursor, ..
+            } => Some(next_page_cursor.as_str()),
+            Self::Spot {
+                next_page_cursor, ..
+            } => next_page_cursor.as_deref(),
+        };
+        cursor.filter(|cursor| !cursor.is_empty())
+    }
+
+    /// Predict the next `count` funding timestamps (ms) after `now` (ms) for every perpetual symbol.
+    /// Option and spot instruments have no funding and are skipped.
+    pub fn funding_schedule(
+        &self,
+        now: Timestamp,
+        count: usize,
+    ) -> HashMap<String, Vec<Timestamp>> {
+        match self {
+            Self::Inverse { list, .. } | Self::Linear { list, .. } => list
+                .iter()
+                .filter(|info| info.funding_interval > 0)
+                .map(|info| (info.symbol.clone(), info.next_funding_times(now, count)))
+                .collect(),
+            Self::Option { .. } | Self::Spot { .. } => HashMap::new(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct OptionInstrumentsInfo {
     pub symbol: String,
-    pub contract_type: ContractType,
     pub status: Status,
     pub base_coin: String,
     pub quote_coin: String,
+    pub settle_coin: String,
+    /// Option type. Call or Put
+    pub options_type: String,
     #[serde(deserialize_with = "number")]
-    pub launch_time: i64,
+    pub launch_time: Timestamp,
     #[serde(deserialize_with = "number")]
-    pub delivery_time: i64,
+    pub delivery_time: Timestamp,
     #[serde(deserialize_with = "option_number")]
     pub delivery_fee_rate: Option<f64>,
-    #[serde(deserialize_with = "number")]
-    pub price_scale: i64,
-    pub leverage_filter: LeverageFilter,
     pub price_filter: PriceFilter,
-    pub lot_size_filter: LotSizeFilter,
-    pub unified_margin_trade: bool,
-    pub funding_interval: i64,
-    pub settle_coin: String,
-    pub copy_trading: CopyTrading,
-    #[serde(deserialize_with = "number")]
-    pub upper_funding_rate: f64,
-    #[serde(deserialize_with = "number")]
-    pub lower_funding_rate: f64,
-    pub risk_parameters: RiskParameters,
-    pub is_pre_listing: bool,
-    pub pre_listing_info: Option<PreListingInfo>,
+    pub lot_size_filter: OptionLotSizeFilter,
+    pub display_name: String,
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
@@ -541,60 +850,52 @@ pub struct LeverageFilter {
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceFilter {
-    #[serde(deserialize_with = "number")]
-    pub min_price: f64,
-    #[serde(deserialize_with = "number")]
-    pub max_price: f64,
-    #[serde(deserialize_with = "number")]
-    pub tick_size: f64,
+    pub min_price: Decimal,
+    pub max_price: Decimal,
+    pub tick_size: Decimal,
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotPriceFilter {
     /// The step to increase/reduce order price
-    #[serde(deserialize_with = "number")]
-    pub tick_size: f64,
+    pub tick_size: Decimal,
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct LotSizeFilter {
-    #[serde(deserialize_with = "number")]
-    pub min_notional_value: f64,
-    #[serde(deserialize_with = "number")]
-    pub max_order_qty: f64,
-    #[serde(deserialize_with = "number")]
-    pub max_mkt_order_qty: f64,
-    #[serde(deserialize_with = "number")]
-    pub min_order_qty: f64,
-    #[serde(deserialize_with = "number")]
-    pub qty_step: f64,
-    #[serde(deserialize_with = "number")]
-    pub post_only_max_order_qty: f64,
+    pub min_notional_value: Decimal,
+    pub max_order_qty: Decimal,
+    pub max_mkt_order_qty: Decimal,
+    pub min_order_qty: Decimal,
+    pub qty_step: Decimal,
+    pub post_only_max_order_qty: Decimal,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionLotSizeFilter {
+    pub max_order_qty: Decimal,
+    pub min_order_qty: Decimal,
+    pub qty_step: Decimal,
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotLotSizeFilter {
     /// The precision of base coin
-    #[serde(deserialize_with = "number")]
-    pub base_precision: f64,
+    pub base_precision: Decimal,
     /// The precision of quote coin
-    #[serde(deserialize_with = "number")]
-    pub quote_precision: f64,
+    pub quote_precision: Decimal,
     /// Minimum order quantity
-    #[serde(deserialize_with = "number")]
-    pub min_order_qty: f64,
+    pub min_order_qty: Decimal,
     /// Maximum order quantity
-    #[serde(deserialize_with = "number")]
-    pub max_order_qty: f64,
+    pub max_order_qty: Decimal,
     /// Minimum order amount
-    #[serde(deserialize_with = "number")]
-    pub min_order_amt: f64,
+    pub min_order_amt: Decimal,
     /// Maximum order amount
-    #[serde(deserialize_with = "number")]
-    pub max_order_amt: f64,
+    pub max_order_amt: Decimal,
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
@@ -637,312 +938,979 @@ pub struct AuctionFeeInfo {
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct GetOpenClosedOrdersParams {
-    /// Product type
-    /// UTA2.0, UTA1.0: linear, inverse, spot, option
-    /// classic account: linear, inverse, spot
+#[non_exhaustive]
+pub struct GetOpenInterestParams {
+    /// Product type. linear, inverse
     pub category: Category,
-    /// Symbol name, like BTCUSDT, uppercase only. For linear, either symbol, baseCoin, settleCoin is required
-    pub symbol: Option<String>,
-    /// Base coin, uppercase only
-    /// Supports linear, inverse & option
-    /// option: it returns all option open orders by default
-    pub base_coin: Option<String>,
-    /// Settle coin, uppercase only
-    /// linear: either symbol, baseCoin or settleCoin is required
-    /// spot: not supported
-    /// option: USDT or USDC
-    pub settle_coin: Option<String>,
-    /// Order ID
-    pub order_id: Option<String>,
-    /// User customised order ID
-    pub order_link_id: Option<String>,
-    /// 0(default): UTA2.0, UTA1.0, classic account query open status orders (e.g., New, PartiallyFilled) only
-    /// 1: UTA2.0, UTA1.0(except inverse)
-    /// 2: UTA1.0(inverse), classic account
-    /// Query a maximum of recent 500 closed status records are kept under each account each category (e.g., Cancelled, Rejected, Filled orders).
-    /// If the Bybit service is restarted due to an update, this part of the data will be cleared and accumulated again, but the order records will still be queried in order history
-    /// openOnly param will be ignored when query by orderId or orderLinkId
-    /// Classic spot: not supported
-    pub open_only: Option<i32>,
-    /// Order: active order,
-    /// StopOrder: conditional order for Futures and Spot,
-    /// tpslOrder: spot TP/SL order,
-    /// OcoOrder: Spot oco order,
-    /// BidirectionalTpslOrder: Spot bidirectional TPSL order
-    /// - classic account spot: return Order active order by default
-    /// - Others: all kinds of orders by default
-    #[serde(default, deserialize_with = "invalid_as_none")]
-    pub order_filter: Option<OrderFilter>,
-    /// Limit for data size per page. [1, 50]. Default: 20
-    pub limit: Option<i32>,
+    /// Symbol name, uppercase only
+    pub symbol: String,
+    /// Interval time. 5min, 15min, 30min, 1h, 4h, 1d
+    pub interval_time: IntervalTime,
+    /// The start timestamp (ms)
+    pub start_time: Option<Timestamp>,
+    /// The end timestamp (ms)
+    pub end_time: Option<Timestamp>,
+    /// Limit for data size per page. [1, 200]. Default: 50
+    pub limit: Option<u64>,
     /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
     pub cursor: Option<String>,
 }
 
+impl GetOpenInterestParams {
+    pub fn new(category: Category, symbol: impl Into<String>, interval_time: IntervalTime) -> Self {
+        Self {
+            category,
+            symbol: symbol.into(),
+            interval_time,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+
+    pub fn start_time(mut self, start_time: Timestamp) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: Timestamp) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    /// Open interest. The value is the sum of both sides.
+    /// The unit of value, e.g., BTCUSD(inverse) is USD, BTCUSDT(linear) is BTC
+    pub open_interest: Decimal,
+    /// The timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub timestamp: Timestamp,
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
-pub enum OrderFilter {
-    /// active order,
-    Order,
-    /// conditional order for Futures and Spot,
-    StopOrder,
-    /// spot TP/SL order,
-    TpslOrder,
-    /// Spot oco order,
-    OcoOrder,
-    /// Spot bidirectional TPSL order
-    /// - classic account spot: return Order active order by default
-    /// - Others: all kinds of orders by default
-    BidirectionalTpslOrder,
+#[non_exhaustive]
+pub struct GetHistoricalVolatilityParams {
+    /// Product type. option
+    pub category: Category,
+    /// Base coin, uppercase only. Default: return BTC data
+    pub base_coin: Option<String>,
+    /// Quote coin, USD or USDT. Default: return quoteCoin=USD
+    pub quote_coin: Option<String>,
+    /// Period. If not specified, it will return data with a 7-day average by default
+    pub period: Option<OptionPeriod>,
+    /// The start timestamp (ms)
+    /// startTime and endTime must be passed together or both are not passed.
+    /// [endTime - 30 days, endTime] are supported
+    pub start_time: Option<Timestamp>,
+    /// The end timestamp (ms)
+    pub end_time: Option<Timestamp>,
+}
+
+impl GetHistoricalVolatilityParams {
+    pub fn new(category: Category) -> Self {
+        Self {
+            category,
+            base_coin: None,
+            quote_coin: None,
+            period: None,
+            start_time: None,
+            end_time: None,
+        }
+    }
+
+    pub fn base_coin(mut self, base_coin: impl Into<String>) -> Self {
+        self.base_coin = Some(base_coin.into());
+        self
+    }
+
+    pub fn quote_coin(mut self, quote_coin: impl Into<String>) -> Self {
+        self.quote_coin = Some(quote_coin.into());
+        self
+    }
+
+    pub fn period(mut self, period: OptionPeriod) -> Self {
+        self.period = Some(period);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: Timestamp) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: Timestamp) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct Order {
-    /// Order ID
-    pub order_id: String,
-    /// User customised order ID
-    #[serde(default, deserialize_with = "empty_string_as_none")]
-    pub order_link_id: Option<String>,
-    /// Paradigm block trade ID
+pub struct HistoricalVolatility {
+    /// Period (days)
+    pub period: u64,
+    /// Volatility
+    pub value: Decimal,
+    /// Timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub time: Timestamp,
+}
+
+#[derive(Serialize, Default)]
+#[non_exhaustive]
+pub struct GetInsuranceParams {
+    /// Coin, uppercase only. Default: return all insurance coins
+    pub coin: Option<String>,
+}
+
+impl GetInsuranceParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn coin(mut self, coin: impl Into<String>) -> Self {
+        self.coin = Some(coin.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Insurance {
+    /// Data updated time (ms)
+    #[serde(deserialize_with = "number")]
+    pub updated_time: Timestamp,
+    pub list: Vec<InsuranceFund>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InsuranceFund {
+    /// Coin
+    pub coin: String,
+    /// Symbols with "," separated which share the insurance pool. Empty for the shared pool
     #[serde(default, deserialize_with = "empty_string_as_none")]
-    pub block_trade_id: Option<String>,
+    pub symbols: Option<String>,
+    /// Balance
+    pub balance: Decimal,
+    /// USD value
+    pub value: Decimal,
+}
+
+#[derive(Serialize)]
+#[non_exhaustive]
+pub struct GetRiskLimitParams {
+    /// Product type. linear, inverse
+    pub category: Category,
+    /// Symbol name, uppercase only
+    pub symbol: Option<String>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetRiskLimitParams {
+    pub fn new(category: Category) -> Self {
+        Self {
+            category,
+            symbol: None,
+            cursor: None,
+        }
+    }
+
+    pub fn linear(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Linear).symbol(symbol)
+    }
+
+    pub fn inverse(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Inverse).symbol(symbol)
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RiskLimit {
+    /// Risk ID
+    pub id: i64,
     /// Symbol name
     pub symbol: String,
-    /// Order price
-    pub price: Decimal,
-    /// Order qty
-    pub qty: Decimal,
-    /// Side. Buy,Sell
-    pub side: Side,
-    /// Whether to borrow. Unified spot only. 0: false, 1: true. Classic spot is not supported, always 0
-    #[serde(default, deserialize_with = "string_to_option_bool")]
-    pub is_leverage: Option<bool>,
-    /// Position index. Used to identify positions in different position modes.
-    pub position_idx: PositionIdx,
-    /// Order status
-    pub order_status: OrderStatus,
-    /// Order create type
-    /// Only for category=linear or inverse
-    /// Spot, Option do not have this key
-    #[serde(default, deserialize_with = "invalid_as_none")]
-    pub create_type: Option<CreateType>,
-    /// Cancel type
-    pub cancel_type: CancelType,
-    /// Reject reason. Classic spot is not supported
-    pub reject_reason: RejectReason,
-    /// Average filled price
-    /// UTA: returns "" for those orders without avg price
-    /// classic account: returns "0" for those orders without avg price, and also for those orders have partilly filled but cancelled at the end
-    #[serde(default, deserialize_with = "option_decimal")]
-    pub avg_price: Option<Decimal>,
-    /// The remaining qty not executed. Classic spot is not supported
-    pub leaves_qty: Decimal,
-    /// The estimated value not executed. Classic spot is not supported
-    pub leaves_value: Decimal,
-    /// Cumulative executed order qty
-    pub cum_exec_qty: Decimal,
-    /// Cumulative executed order value. Classic spot is not supported
-    pub cum_exec_value: Decimal,
-    /// Cumulative executed trading fee. Classic spot is not supported
-    pub cum_exec_fee: Decimal,
-    /// Time in force
-    pub time_in_force: TimeInForce,
-    /// Order type. Market,Limit. For TP/SL order, it means the order type after triggered
-    pub order_type: OrderType,
-    /// Stop order type
-    #[serde(default, deserialize_with = "invalid_as_none")]
-    pub stop_order_type: Option<StopOrderType>,
-    /// Implied volatility
-    #[serde(default, deserialize_with = "empty_string_as_none")]
-    pub order_iv: Option<String>,
-    /// The unit for qty when create Spot market orders for UTA account. baseCoin, quoteCoin
-    #[serde(default, deserialize_with = "empty_string_as_none")]
-    pub market_unit: Option<String>,
-    /// Trigger price. If stopOrderType=TrailingStop, it is activate price. Otherwise, it is trigger price
-    #[serde(default, deserialize_with = "option_decimal")]
-    pub trigger_price: Option<Decimal>,
-    /// Take profit price
-    #[serde(default, deserialize_with = "option_decimal")]
-    pub take_profit: Option<Decimal>,
-    /// Stop loss price
-    #[serde(default, deserialize_with = "option_decimal")]
-    pub stop_loss: Option<Decimal>,
-    /// TP/SL mode, Full: entire position for TP/SL. Partial: partial position tp/sl. Spot does not have this field, and Option returns always ""
-    #[serde(default, deserialize_with = "invalid_as_none")]
-    pub tpsl_mode: Option<TpslMode>,
-    /// The trigger type of Spot OCO order.OcoTriggerByUnknown, OcoTriggerByTp, OcoTriggerByBySl. Classic spot is not supported
-    #[serde(default, deserialize_with = "invalid_as_none")]
-    pub oco_trigger_by: Option<OcoTriggerBy>,
-    /// The limit order price when take profit price is triggered
-    #[serde(default, deserialize_with = "option_decimal")]
-    pub tp_limit_price: Option<Decimal>,
-    /// The limit order price when stop loss price is triggered
-    #[serde(default, deserialize_with = "option_decimal")]
-    pub sl_limit_price: Option<Decimal>,
-    /// The price type to trigger take profit
-    #[serde(default, deserialize_with = "invalid_as_none")]
-    pub tp_trigger_by: Option<TriggerBy>,
-    /// The price type to trigger stop loss
-    #[serde(default, deserialize_with = "invalid_as_none")]
-    pub sl_trigger_by: Option<TriggerBy>,
-    /// Trigger direction. 1: rise, 2: fall
-    pub trigger_direction: TriggerDirection,
-    /// The price type of trigger price
-    pub trigger_by: TriggerBy,
-    /// Last price when place the order, Spot is not applicable
-    #[serde(default, deserialize_with = "option_decimal")]
-    pub last_price_on_created: Option<Decimal>,
-    /// Last price when place the order, Spot has this field only
+    /// Position limit
+    pub risk_limit_value: Decimal,
+    /// Maintain margin rate
+    pub maintenance_margin: Decimal,
+    /// Initial margin rate
+    pub initial_margin: Decimal,
+    /// 1: true, 0: false
+    #[serde(deserialize_with = "int_to_bool")]
+    pub is_lowest_risk: bool,
+    /// Allowed max leverage
+    pub max_leverage: Decimal,
+    /// The maintenance margin deduction value when risk limit tier changed
     #[serde(default, deserialize_with = "option_decimal")]
-    pub base_price: Option<Decimal>,
-    /// Reduce only. true means reduce position size
-    pub reduce_only: bool,
-    /// Close on trigger. What is a close on trigger order?
-    pub close_on_trigger: bool,
-    /// Place type, option used. iv, price
-    #[serde(default, deserialize_with = "invalid_as_none")]
-    pub place_type: Option<PlaceType>,
-    /// SMP execution type
-    pub smp_type: SmpType,
-    /// Smp group ID. If the UID has no group, it is 0 by default
-    pub smp_group: i64,
-    /// The counterparty's orderID which triggers this SMP execution
-    #[serde(default, deserialize_with = "empty_string_as_none")]
-    pub smp_order_id: Option<String>,
-    /// Order created timestamp (ms)
-    #[serde(deserialize_with = "number")]
-    pub created_time: Timestamp,
-    /// Order updated timestamp (ms)
-    #[serde(deserialize_with = "number")]
-    pub updated_time: Timestamp,
+    pub mm_deduction: Option<Decimal>,
 }
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct GetPositionInfoParams {
-    /// Product type
-    /// UTA2.0, UTA1.0: linear, inverse, option
-    /// Classic account: linear, inverse
+#[non_exhaustive]
+pub struct GetDeliveryPriceParams {
+    /// Product type. linear, inverse, option
     pub category: Category,
     /// Symbol name, like BTCUSDT, uppercase only
-    /// If symbol passed, it returns data regardless of having position or not.
-    /// If symbol=null and settleCoin specified, it returns position size greater than zero.
     pub symbol: Option<String>,
-    /// Base coin, uppercase only. option only. Return all option positions if not passed
+    /// Base coin, uppercase only. Default: BTC. Valid for option only
     pub base_coin: Option<String>,
-    /// Settle coin
-    /// linear: either symbol or settleCoin is required. symbol has a higher priority
+    /// Settle coin, uppercase only. Default: USDC. Valid for option only
     pub settle_coin: Option<String>,
-    /// Limit for data size per page. [1, 200]. Default: 20
+    /// Limit for data size per page. [1, 200]. Default: 50
     pub limit: Option<u64>,
     /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
     pub cursor: Option<String>,
 }
 
+impl GetDeliveryPriceParams {
+    pub fn new(category: Category) -> Self {
+        Self {
+            category,
+            symbol: None,
+            base_coin: None,
+            settle_coin: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+
+    pub fn linear(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Linear).symbol(symbol)
+    }
+
+    pub fn inverse(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Inverse).symbol(symbol)
+    }
+
+    pub fn option(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Option).symbol(symbol)
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn base_coin(mut self, base_coin: impl Into<String>) -> Self {
+        self.base_coin = Some(base_coin.into());
+        self
+    }
+
+    pub fn settle_coin(mut self, settle_coin: impl Into<String>) -> Self {
+        self.settle_coin = Some(settle_coin.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct Position {
-    /// Position idx, used to identify positions in different position modes
-    /// 0: One-Way Mode
-    /// 1: Buy side of both side mode
-    /// 2: Sell side of both side mode
-    pub position_idx: PositionIdx,
-    /// Risk tier ID
-    /// for portfolio margin mode, this field returns 0, which means risk limit rules are invalid
-    pub risk_id: i64,
-    /// Risk limit value
-    /// for portfolio margin mode, this field returns 0, which means risk limit rules are invalid
-    pub risk_limit_value: Decimal,
+pub struct DeliveryPrice {
     /// Symbol name
     pub symbol: String,
-    /// Position side. Buy: long, Sell: short
-    /// one-way mode: classic & UTA1.0(inverse), an empty position returns None.
-    /// UTA2.0(linear, inverse) & UTA1.0(linear): either one-way or hedge mode returns an empty string "" for an empty position.
-    #[serde(default, deserialize_with = "invalid_as_none")]
-    pub side: Option<Side>,
-    /// Position size, always positive
-    pub size: Decimal,
-    /// Average entry price
-    /// For USDC Perp & Futures, it indicates average entry price, and it will not be changed with 8-hour session settlement
-    pub avg_price: Decimal,
-    /// Position value
-    #[serde(default, deserialize_with = "option_decimal")]
-    pub position_value: Option<Decimal>,
-    /// Trade mode
-    /// Classic & UTA1.0(inverse): 0: cross-margin, 1: isolated margin
-    /// UTA2.0, UTA1.0(execpt inverse): deprecated, always 0, check Get Account Info to know the margin mode
-    pub trade_mode: TradeMode,
-    /// Whether to add margin automatically when using isolated margin mode
-    /// 0: false
-    /// 1: true
-    #[serde(deserialize_with = "int_to_bool")]
-    pub auto_add_margin: bool,
-    /// Position status. Normal, Liq, Adl
-    pub position_status: PositionStatus,
-    /// Position leverage
-    /// for portfolio margin mode, this field returns "", which means leverage rules are invalid
-    pub leverage: Decimal,
-    /// Mark price
-    pub mark_price: Decimal,
-    /// Position liquidation price
-    /// UTA2.0(isolated margin), UTA1.0(isolated margin), UTA1.0(inverse), Classic account:
-    /// it is the real price for isolated and cross positions, and keeps "" when liqPrice <= minPrice or liqPrice >= maxPrice
-    /// UTA2.0(Cross margin), UTA1.0(Cross margin):
-    /// it is an estimated price for cross positions(because the unified mode controls the risk rate according to the account), and keeps "" when liqPrice <= minPrice or liqPrice >= maxPrice
-    /// this field is empty for Portfolio Margin Mode, and no liquidation price will be provided
-    #[serde(default, deserialize_with = "option_decimal")]
-    pub liq_price: Option<Decimal>,
-    /// Bankruptcy price
-    #[serde(default, deserialize_with = "option_decimal")]
-    pub bust_price: Option<Decimal>,
-    /// Initial margin
-    /// Classic & UTA1.0(inverse): ignore this field
-    /// UTA portfolio margin mode, it returns ""
-    #[serde(rename = "positionIM")]
-    pub position_im: Decimal,
-    /// Maintenance margin
-    /// Classic & UTA1.0(inverse): ignore this field
-    /// UTA portfolio margin mode, it returns ""
-    #[serde(rename = "positionMM")]
-    pub position_mm: Decimal,
-    /// Position margin
-    /// Classic & UTA1.0(inverse) can refer to this field to get the position initial margin plus position closing fee
-    pub position_balance: Decimal,
-    /// Take profit price
-    #[serde(default, deserialize_with = "option_decimal")]
-    pub take_profit: Option<Decimal>,
-    /// Stop loss price
-    #[serde(default, deserialize_with = "option_decimal")]
-    pub stop_loss: Option<Decimal>,
-    /// Trailing stop (The distance from market price)
-    pub trailing_stop: Decimal,
-    /// USDC contract session avg price, it is the same figure as avg entry price shown in the web UI
-    #[serde(default, deserialize_with = "option_decimal")]
-    pub session_avg_price: Option<Decimal>,
-    /// Delta
+    /// Delivery price
+    pub delivery_price: Decimal,
+    /// Delivery timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub delivery_time: Timestamp,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetLongShortRatioParams {
+    /// Product type. linear(USDT Contract), inverse
+    pub category: Category,
+    /// Symbol name, uppercase only
+    pub symbol: String,
+    /// Data recording period. 5min, 15min, 30min, 1h, 4h, 4d
+    pub period: DataRecordingPeriod,
+    /// The start timestamp (ms)
+    pub start_time: Option<Timestamp>,
+    /// The end timestamp (ms)
+    pub end_time: Option<Timestamp>,
+    /// Limit for data size per page. [1, 500]. Default: 50
+    pub limit: Option<u64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetLongShortRatioParams {
+    pub fn new(category: Category, symbol: impl Into<String>, period: DataRecordingPeriod) -> Self {
+        Self {
+            category,
+            symbol: symbol.into(),
+            period,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+
+    pub fn start_time(mut self, start_time: Timestamp) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: Timestamp) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatio {
+    /// Symbol name
+    pub symbol: String,
+    /// The ratio of the number of long position
+    pub buy_ratio: Decimal,
+    /// The ratio of the number of short position
+    pub sell_ratio: Decimal,
+    /// Timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub timestamp: Timestamp,
+}
+
+#[derive(Serialize, Default)]
+#[non_exhaustive]
+pub struct GetSystemStatusParams {
+    /// Id. Unique identifier
+    pub id: Option<String>,
+    /// System state
+    pub state: Option<SystemState>,
+}
+
+impl GetSystemStatusParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn state(mut self, state: SystemState) -> Self {
+        self.state = Some(state);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Maintenance {
+    /// Id. Unique identifier
+    pub id: String,
+    /// Title of system maintenance
+    pub title: String,
+    /// System state
+    pub state: SystemState,
+    /// Start time of system maintenance, timestamp in milliseconds
+    #[serde(deserialize_with = "number")]
+    pub begin: Timestamp,
+    /// End time of system maintenance, timestamp in milliseconds. Before maintenance is completed, it is the expected end time; After maintenance is completed, it will be changed to the actual end time.
+    #[serde(deserialize_with = "number")]
+    pub end: Timestamp,
+    /// Hyperlink to system maintenance details. Default value is empty string
     #[serde(default, deserialize_with = "empty_string_as_none")]
-    pub delta: Option<String>,
-    /// Gamma
+    pub href: Option<String>,
+    /// Service Type
+    pub service_types: Vec<i64>,
+    /// Product
+    pub product: Vec<i64>,
+    /// Affected UID tail number
+    pub uid_suffix: Vec<i64>,
+    /// Maintenance type
+    pub maintain_type: String,
+    /// Environment
+    pub env: String,
+}
+
+#[derive(Serialize)]
+#[non_exhaustive]
+pub struct GetAnnouncementsParams {
+    /// Language symbol
+    pub locale: Locale,
+    /// Announcement type
+    #[serde(rename = "type")]
+    pub announcement_type: Option<AnnouncementType>,
+    /// Announcement tag
+    pub tag: Option<String>,
+    /// Page number. Default: 1
+    pub page: Option<u64>,
+    /// Limit for data size per page. Default: 20
+    pub limit: Option<u64>,
+}
+
+impl GetAnnouncementsParams {
+    pub fn new(locale: Locale) -> Self {
+        Self {
+            locale,
+            announcement_type: None,
+            tag: None,
+            page: None,
+            limit: None,
+        }
+    }
+
+    pub fn announcement_type(mut self, announcement_type: AnnouncementType) -> Self {
+        self.announcement_type = Some(announcement_type);
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Announcements {
+    /// Total records
+    pub total: u64,
+    pub list: Vec<Announcement>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Announcement {
+    /// Announcement title
+    pub title: String,
+    /// Announcement description
+    pub description: String,
+    /// Announcement type
+    #[serde(rename = "type")]
+    pub announcement_type: AnnouncementTypeInfo,
+    /// Announcement tag
+    pub tags: Vec<String>,
+    /// Announcement url
+    pub url: String,
+    /// Timestamp that author fills
+    pub date_timestamp: Timestamp,
+    /// The start timestamp (ms) of the event, only valid when list.type.key == "latest_activities"
+    #[serde(default)]
+    pub start_date_timestamp: Option<Timestamp>,
+    /// The end timestamp (ms) of the event, only valid when list.type.key == "latest_activities"
+    #[serde(default)]
+    pub end_date_timestamp: Option<Timestamp>,
+    /// The published timestamp for the announcement
+    #[serde(default)]
+    pub publish_time: Option<Timestamp>,
+}
+
+impl Announcement {
+    pub fn is_delisting(&self) -> bool {
+        self.announcement_type.key == AnnouncementType::Delistings
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct AnnouncementTypeInfo {
+    /// The title of announcement type
+    pub title: String,
+    /// The key of announcement type
+    pub key: AnnouncementType,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetOpenClosedOrdersParams {
+    /// Product type
+    /// UTA2.0, UTA1.0: linear, inverse, spot, option
+    /// classic account: linear, inverse, spot
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only. For linear, either symbol, baseCoin, settleCoin is required
+    pub symbol: Option<String>,
+    /// Base coin, uppercase only
+    /// Supports linear, inverse & option
+    /// option: it returns all option open orders by default
+    pub base_coin: Option<String>,
+    /// Settle coin, uppercase only
+    /// linear: either symbol, baseCoin or settleCoin is required
+    /// spot: not supported
+    /// option: USDT or USDC
+    pub settle_coin: Option<String>,
+    /// Order ID
+    pub order_id: Option<String>,
+    /// User customised order ID
+    pub order_link_id: Option<String>,
+    /// 0(default): UTA2.0, UTA1.0, classic account query open status orders (e.g., New, PartiallyFilled) only
+    /// 1: UTA2.0, UTA1.0(except inverse)
+    /// 2: UTA1.0(inverse), classic account
+    /// Query a maximum of recent 500 closed status records are kept under each account each category (e.g., Cancelled, Rejected, Filled orders).
+    /// If the Bybit service is restarted due to an update, this part of the data will be cleared and accumulated again, but the order records will still be queried in order history
+    /// openOnly param will be ignored when query by orderId or orderLinkId
+    /// Classic spot: not supported
+    pub open_only: Option<i32>,
+    /// Order: active order,
+    /// StopOrder: conditional order for Futures and Spot,
+    /// tpslOrder: spot TP/SL order,
+    /// OcoOrder: Spot oco order,
+    /// BidirectionalTpslOrder: Spot bidirectional TPSL order
+    /// - classic account spot: return Order active order by default
+    /// - Others: all kinds of orders by default
+    #[serde(default, deserialize_with = "invalid_as_none")]
+    pub order_filter: Option<OrderFilter>,
+    /// Limit for data size per page. [1, 50]. Default: 20
+    pub limit: Option<i32>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetOpenClosedOrdersParams {
+    pub fn new(category: Category) -> Self {
+        Self {
+            category,
+            symbol: None,
+            base_coin: None,
+            settle_coin: None,
+            order_id: None,
+            order_link_id: None,
+            open_only: None,
+            order_filter: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+
+    pub fn spot(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Spot).symbol(symbol)
+    }
+
+    pub fn linear(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Linear).symbol(symbol)
+    }
+
+    pub fn inverse(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Inverse).symbol(symbol)
+    }
+
+    pub fn option(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Option).symbol(symbol)
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn base_coin(mut self, base_coin: impl Into<String>) -> Self {
+        self.base_coin = Some(base_coin.into());
+        self
+    }
+
+    pub fn settle_coin(mut self, settle_coin: impl Into<String>) -> Self {
+        self.settle_coin = Some(settle_coin.into());
+        self
+    }
+
+    pub fn order_id(mut self, order_id: impl Into<String>) -> Self {
+        self.order_id = Some(order_id.into());
+        self
+    }
+
+    pub fn order_link_id(mut self, order_link_id: impl Into<String>) -> Self {
+        self.order_link_id = Some(order_link_id.into());
+        self
+    }
+
+    pub fn open_only(mut self, open_only: i32) -> Self {
+        self.open_only = Some(open_only);
+        self
+    }
+
+    pub fn order_filter(mut self, order_filter: OrderFilter) -> Self {
+        self.order_filter = Some(order_filter);
+        self
+    }
+
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OrderFilter {
+    /// active order,
+    Order,
+    /// conditional order for Futures and Spot,
+    StopOrder,
+    /// spot TP/SL order,
+    TpslOrder,
+    /// Spot oco order,
+    OcoOrder,
+    /// Spot bidirectional TPSL order
+    /// - classic account spot: return Order active order by default
+    /// - Others: all kinds of orders by default
+    BidirectionalTpslOrder,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    /// Order ID
+    pub order_id: String,
+    /// User customised order ID
     #[serde(default, deserialize_with = "empty_string_as_none")]
-    pub gamma: Option<String>,
-    /// Vega
+    pub order_link_id: Option<String>,
+    /// Paradigm block trade ID
     #[serde(default, deserialize_with = "empty_string_as_none")]
-    pub vega: Option<String>,
-    /// Theta
+    pub block_trade_id: Option<String>,
+    /// Symbol name
+    pub symbol: String,
+    /// Order price
+    pub price: Decimal,
+    /// Order qty
+    pub qty: Decimal,
+    /// Side. Buy,Sell
+    pub side: Side,
+    /// Whether to borrow. Unified spot only. 0: false, 1: true. Classic spot is not supported, always 0
+    #[serde(default, deserialize_with = "string_to_option_bool")]
+    pub is_leverage: Option<bool>,
+    /// Position index. Used to identify positions in different position modes.
+    pub position_idx: PositionIdx,
+    /// Order status
+    pub order_status: OrderStatus,
+    /// Order create type
+    /// Only for category=linear or inverse
+    /// Spot, Option do not have this key
+    #[serde(default, deserialize_with = "invalid_as_none")]
+    pub create_type: Option<CreateType>,
+    /// Cancel type
+    pub cancel_type: CancelType,
+    /// Reject reason. Classic spot is not supported
+    pub reject_reason: RejectReason,
+    /// Average filled price
+    /// UTA: returns "" for those orders without avg price
+    /// classic account: returns "0" for those orders without avg price, and also for those orders have partilly filled but cancelled at the end
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub avg_price: Option<Decimal>,
+    /// The remaining qty not executed. Classic spot is not supported
+    pub leaves_qty: Decimal,
+    /// The estimated value not executed. Classic spot is not supported
+    pub leaves_value: Decimal,
+    /// Cumulative executed order qty
+    pub cum_exec_qty: Decimal,
+    /// Cumulative executed order value. Classic spot is not supported
+    pub cum_exec_value: Decimal,
+    /// Cumulative executed trading fee. Classic spot is not supported
+    pub cum_exec_fee: Decimal,
+    /// Time in force
+    pub time_in_force: TimeInForce,
+    /// Order type. Market,Limit. For TP/SL order, it means the order type after triggered
+    pub order_type: OrderType,
+    /// Stop order type
+    #[serde(default, deserialize_with = "invalid_as_none")]
+    pub stop_order_type: Option<StopOrderType>,
+    /// Implied volatility
     #[serde(default, deserialize_with = "empty_string_as_none")]
-    pub theta: Option<String>,
-    /// Unrealised PnL
+    pub order_iv: Option<String>,
+    /// The unit for qty when create Spot market orders for UTA account. baseCoin, quoteCoin
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub market_unit: Option<String>,
+    /// Trigger price. If stopOrderType=TrailingStop, it is activate price. Otherwise, it is trigger price
     #[serde(default, deserialize_with = "option_decimal")]
-    pub unrealised_pnl: Option<Decimal>,
-    /// The realised PnL for the current holding position
-    pub cur_realised_pnl: Decimal,
-    /// Cumulative realised pnl
-    /// Futures & Perps: it is the all time cumulative realised P&L
-    /// Option: always "", meaningless
-    pub cum_realised_pnl: Decimal,
-    /// Auto-deleverage rank indicator. What is Auto-Deleveraging?
+    pub trigger_price: Option<Decimal>,
+    /// Take profit price
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub take_profit: Option<Decimal>,
+    /// Stop loss price
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub stop_loss: Option<Decimal>,
+    /// TP/SL mode, Full: entire position for TP/SL. Partial: partial position tp/sl. Spot does not have this field, and Option returns always ""
+    #[serde(default, deserialize_with = "invalid_as_none")]
+    pub tpsl_mode: Option<TpslMode>,
+    /// The trigger type of Spot OCO order.OcoTriggerByUnknown, OcoTriggerByTp, OcoTriggerByBySl. Classic spot is not supported
+    #[serde(default, deserialize_with = "invalid_as_none")]
+    pub oco_trigger_by: Option<OcoTriggerBy>,
+    /// The limit order price when take profit price is triggered
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub tp_limit_price: Option<Decimal>,
+    /// The limit order price when stop loss price is triggered
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub sl_limit_price: Option<Decimal>,
+    /// The price type to trigger take profit
+    #[serde(default, deserialize_with = "invalid_as_none")]
+    pub tp_trigger_by: Option<TriggerBy>,
+    /// The price type to trigger stop loss
+    #[serde(default, deserialize_with = "invalid_as_none")]
+    pub sl_trigger_by: Option<TriggerBy>,
+    /// Trigger direction. 1: rise, 2: fall
+    pub trigger_direction: TriggerDirection,
+    /// The price type of trigger price
+    pub trigger_by: TriggerBy,
+    /// Last price when place the order, Spot is not applicable
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub last_price_on_created: Option<Decimal>,
+    /// Last price when place the order, Spot has this field only
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub base_price: Option<Decimal>,
+    /// Reduce only. true means reduce position size
+    pub reduce_only: bool,
+    /// Close on trigger. What is a close on trigger order?
+    pub close_on_trigger: bool,
+    /// Place type, option used. iv, price
+    #[serde(default, deserialize_with = "invalid_as_none")]
+    pub place_type: Option<PlaceType>,
+    /// SMP execution type
+    pub smp_type: SmpType,
+    /// Smp group ID. If the UID has no group, it is 0 by default
+    pub smp_group: i64,
+    /// The counterparty's orderID which triggers this SMP execution
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub smp_order_id: Option<String>,
+    /// Order created timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub created_time: Timestamp,
+    /// Order updated timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub updated_time: Timestamp,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetPositionInfoParams {
+    /// Product type
+    /// UTA2.0, UTA1.0: linear, inverse, option
+    /// Classic account: linear, inverse
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    /// If symbol passed, it returns data regardless of having position or not.
+    /// If symbol=null and settleCoin specified, it returns position size greater than zero.
+    pub symbol: Option<String>,
+    /// Base coin, uppercase only. option only. Return all option positions if not passed
+    pub base_coin: Option<String>,
+    /// Settle coin
+    /// linear: either symbol or settleCoin is required. symbol has a higher priority
+    pub settle_coin: Option<String>,
+    /// Limit for data size per page. [1, 200]. Default: 20
+    pub limit: Option<u64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetPositionInfoParams {
+    pub fn new(category: Category) -> Self {
+        Self {
+            category,
+            symbol: None,
+            base_coin: None,
+            settle_coin: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+
+    pub fn linear(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Linear).symbol(symbol)
+    }
+
+    pub fn inverse(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Inverse).symbol(symbol)
+    }
+
+    pub fn option(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Option).symbol(symbol)
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn base_coin(mut self, base_coin: impl Into<String>) -> Self {
+        self.base_coin = Some(base_coin.into());
+        self
+    }
+
+    pub fn settle_coin(mut self, settle_coin: impl Into<String>) -> Self {
+        self.settle_coin = Some(settle_coin.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    /// Position idx, used to identify positions in different position modes
+    /// 0: One-Way Mode
+    /// 1: Buy side of both side mode
+    /// 2: Sell side of both side mode
+    pub position_idx: PositionIdx,
+    /// Risk tier ID
+    /// for portfolio margin mode, this field returns 0, which means risk limit rules are invalid
+    pub risk_id: i64,
+    /// Risk limit value
+    /// for portfolio margin mode, this field returns 0, which means risk limit rules are invalid
+    pub risk_limit_value: Decimal,
+    /// Symbol name
+    pub symbol: String,
+    /// Position side. Buy: long, Sell: short
+    /// one-way mode: classic & UTA1.0(inverse), an empty position returns None.
+    /// UTA2.0(linear, inverse) & UTA1.0(linear): either one-way or hedge mode returns an empty string "" for an empty position.
+    #[serde(default, deserialize_with = "invalid_as_none")]
+    pub side: Option<Side>,
+    /// Position size, always positive
+    pub size: Decimal,
+    /// Average entry price
+    /// For USDC Perp & Futures, it indicates average entry price, and it will not be changed with 8-hour session settlement
+    pub avg_price: Decimal,
+    /// Position value
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub position_value: Option<Decimal>,
+    /// Trade mode
+    /// Classic & UTA1.0(inverse): 0: cross-margin, 1: isolated margin
+    /// UTA2.0, UTA1.0(execpt inverse): deprecated, always 0, check Get Account Info to know the margin mode
+    pub trade_mode: TradeMode,
+    /// Whether to add margin automatically when using isolated margin mode
+    /// 0: false
+    /// 1: true
+    #[serde(deserialize_with = "int_to_bool")]
+    pub auto_add_margin: bool,
+    /// Position status. Normal, Liq, Adl
+    pub position_status: PositionStatus,
+    /// Position leverage
+    /// for portfolio margin mode, this field returns "", which means leverage rules are invalid
+    pub leverage: Decimal,
+    /// Mark price
+    pub mark_price: Decimal,
+    /// Position liquidation price
+    /// UTA2.0(isolated margin), UTA1.0(isolated margin), UTA1.0(inverse), Classic account:
+    /// it is the real price for isolated and cross positions, and keeps "" when liqPrice <= minPrice or liqPrice >= maxPrice
+    /// UTA2.0(Cross margin), UTA1.0(Cross margin):
+    /// it is an estimated price for cross positions(because the unified mode controls the risk rate according to the account), and keeps "" when liqPrice <= minPrice or liqPrice >= maxPrice
+    /// this field is empty for Portfolio Margin Mode, and no liquidation price will be provided
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub liq_price: Option<Decimal>,
+    /// Bankruptcy price
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub bust_price: Option<Decimal>,
+    /// Initial margin
+    /// Classic & UTA1.0(inverse): ignore this field
+    /// UTA portfolio margin mode, it returns ""
+    #[serde(rename = "positionIM")]
+    pub position_im: Decimal,
+    /// Maintenance margin
+    /// Classic & UTA1.0(inverse): ignore this field
+    /// UTA portfolio margin mode, it returns ""
+    #[serde(rename = "positionMM")]
+    pub position_mm: Decimal,
+    /// Position margin
+    /// Classic & UTA1.0(inverse) can refer to this field to get the position initial margin plus position closing fee
+    pub position_balance: Decimal,
+    /// Take profit price
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub take_profit: Option<Decimal>,
+    /// Stop loss price
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub stop_loss: Option<Decimal>,
+    /// Trailing stop (The distance from market price)
+    pub trailing_stop: Decimal,
+    /// USDC contract session avg price, it is the same figure as avg entry price shown in the web UI
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub session_avg_price: Option<Decimal>,
+    /// Delta
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub delta: Option<String>,
+    /// Gamma
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub gamma: Option<String>,
+    /// Vega
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub vega: Option<String>,
+    /// Theta
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub theta: Option<String>,
+    /// Unrealised PnL
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub unrealised_pnl: Option<Decimal>,
+    /// The realised PnL for the current holding position
+    pub cur_realised_pnl: Decimal,
+    /// Cumulative realised pnl
+    /// Futures & Perps: it is the all time cumulative realised P&L
+    /// Option: always "", meaningless
+    pub cum_realised_pnl: Decimal,
+    /// Auto-deleverage rank indicator. What is Auto-Deleveraging?
     pub adl_rank_indicator: AdlRankIndicator,
     /// Timestamp of the first time a position was created on this symbol (ms)
     #[serde(deserialize_with = "number")]
@@ -980,441 +1948,5702 @@ pub struct Position {
     pub tpsl_mode: TpslMode,
 }
 
-#[cfg(test)]
-mod tests {
-    use rust_decimal::dec;
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct EmptyResult {}
+
+/// Result of a request which changes a setting.
+/// Bybit rejects a request that does not change anything, such a rejection is reported as `NotModified`.
+#[derive(Debug, PartialEq)]
+pub enum ModifyOutcome {
+    Modified,
+    NotModified,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SetLeverageParams {
+    /// Product type
+    /// UTA2.0, UTA1.0: linear, inverse
+    /// Classic: linear, inverse
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: String,
+    /// [1, max leverage]
+    /// one-way mode: buyLeverage must be the same as sellLeverage
+    /// Hedge mode: UTA2.0, UTA1.0 isolated margin: buyLeverage and sellLeverage can be different; UTA cross margin: buyLeverage must be the same as sellLeverage
+    pub buy_leverage: Decimal,
+    /// [1, max leverage]
+    pub sell_leverage: Decimal,
+}
+
+impl SetLeverageParams {
+    pub fn new(
+        category: Category,
+        symbol: impl Into<String>,
+        buy_leverage: Decimal,
+        sell_leverage: Decimal,
+    ) -> Self {
+        Self {
+            category,
+            symbol: symbol.into(),
+            buy_leverage,
+            sell_leverage,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SetTradingStopParams {
+    /// Product type
+    /// UTA2.0, UTA1.0: linear, inverse
+    /// Classic: linear, inverse
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: String,
+    /// TP/SL mode
+    /// Full: entire position TP/SL
+    /// Partial: partial position TP/SL
+    pub tpsl_mode: TpslMode,
+    /// Used to identify positions in different position modes.
+    /// 0: one-way mode
+    /// 1: hedge-mode Buy side
+    /// 2: hedge-mode Sell side
+    pub position_idx: PositionIdx,
+    /// Cannot be less than 0, 0 means cancel TP
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit: Option<Decimal>,
+    /// Cannot be less than 0, 0 means cancel SL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss: Option<Decimal>,
+    /// Trailing stop by price distance. Cannot be less than 0, 0 means cancel TS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_stop: Option<Decimal>,
+    /// Take profit trigger price type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_trigger_by: Option<TriggerBy>,
+    /// Stop loss trigger price type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_trigger_by: Option<TriggerBy>,
+    /// Trailing stop trigger price. Trailing stop will be triggered when this price is reached only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_price: Option<Decimal>,
+    /// Take profit size. Valid in TP/SL partial mode, and it must equal to slSize
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_size: Option<Decimal>,
+    /// Stop loss size. Valid in TP/SL partial mode, and it must equal to tpSize
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_size: Option<Decimal>,
+    /// The limit order price when take profit price is triggered. Only works when tpslMode=Partial and tpOrderType=Limit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_limit_price: Option<Decimal>,
+    /// The limit order price when stop loss price is triggered. Only works when tpslMode=Partial and slOrderType=Limit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_limit_price: Option<Decimal>,
+    /// The order type when take profit is triggered. Market(default), Limit
+    /// For tpslMode=Full, it only supports tpOrderType="Market"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_order_type: Option<OrderType>,
+    /// The order type when stop loss is triggered. Market(default), Limit
+    /// For tpslMode=Full, it only supports slOrderType="Market"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_order_type: Option<OrderType>,
+}
+
+impl SetTradingStopParams {
+    pub fn new(
+        category: Category,
+        symbol: impl Into<String>,
+        tpsl_mode: TpslMode,
+        position_idx: PositionIdx,
+    ) -> Self {
+        Self {
+            category,
+            symbol: symbol.into(),
+            tpsl_mode,
+            position_idx,
+            take_profit: None,
+            stop_loss: None,
+            trailing_stop: None,
+            tp_trigger_by: None,
+            sl_trigger_by: None,
+            active_price: None,
+            tp_size: None,
+            sl_size: None,
+            tp_limit_price: None,
+            sl_limit_price: None,
+            tp_order_type: None,
+            sl_order_type: None,
+        }
+    }
+
+    pub fn take_profit(mut self, take_profit: Decimal) -> Self {
+        self.take_profit = Some(take_profit);
+        self
+    }
+
+    pub fn stop_loss(mut self, stop_loss: Decimal) -> Self {
+        self.stop_loss = Some(stop_loss);
+        self
+    }
+
+    pub fn trailing_stop(mut self, trailing_stop: Decimal) -> Self {
+        self.trailing_stop = Some(trailing_stop);
+        self
+    }
+
+    pub fn tp_trigger_by(mut self, tp_trigger_by: TriggerBy) -> Self {
+        self.tp_trigger_by = Some(tp_trigger_by);
+        self
+    }
+
+    pub fn sl_trigger_by(mut self, sl_trigger_by: TriggerBy) -> Self {
+        self.sl_trigger_by = Some(sl_trigger_by);
+        self
+    }
+
+    pub fn active_price(mut self, active_price: Decimal) -> Self {
+        self.active_price = Some(active_price);
+        self
+    }
+
+    pub fn tp_size(mut self, tp_size: Decimal) -> Self {
+        self.tp_size = Some(tp_size);
+        self
+    }
+
+    pub fn sl_size(mut self, sl_size: Decimal) -> Self {
+        self.sl_size = Some(sl_size);
+        self
+    }
+
+    pub fn tp_limit_price(mut self, tp_limit_price: Decimal) -> Self {
+        self.tp_limit_price = Some(tp_limit_price);
+        self
+    }
+
+    pub fn sl_limit_price(mut self, sl_limit_price: Decimal) -> Self {
+        self.sl_limit_price = Some(sl_limit_price);
+        self
+    }
+
+    pub fn tp_order_type(mut self, tp_order_type: OrderType) -> Self {
+        self.tp_order_type = Some(tp_order_type);
+        self
+    }
+
+    pub fn sl_order_type(mut self, sl_order_type: OrderType) -> Self {
+        self.sl_order_type = Some(sl_order_type);
+        self
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SwitchIsolatedParams {
+    /// Product type
+    /// UTA1.0: inverse
+    /// Classic: linear(USDT Preps), inverse
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: String,
+    /// 0: cross margin. 1: isolated margin
+    pub trade_mode: TradeMode,
+    /// The value must be equal to sellLeverage value
+    pub buy_leverage: Decimal,
+    /// The value must be equal to buyLeverage value
+    pub sell_leverage: Decimal,
+}
+
+impl SwitchIsolatedParams {
+    pub fn new(
+        category: Category,
+        symbol: impl Into<String>,
+        trade_mode: TradeMode,
+        buy_leverage: Decimal,
+        sell_leverage: Decimal,
+    ) -> Self {
+        Self {
+            category,
+            symbol: symbol.into(),
+            trade_mode,
+            buy_leverage,
+            sell_leverage,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SwitchPositionModeParams {
+    /// Product type
+    /// UTA2.0, UTA1.0: linear, USDT Contract; inverse, Inverse Futures
+    /// Classic: linear, USDT Perp; inverse, Inverse Futures
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only. Either symbol or coin is required. symbol has a higher priority
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    /// Coin, uppercase only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coin: Option<String>,
+    /// Position mode. 0: Merged Single. 3: Both Sides
+    pub mode: PositionMode,
+}
+
+impl SwitchPositionModeParams {
+    pub fn new(category: Category, mode: PositionMode) -> Self {
+        Self {
+            category,
+            mode,
+            symbol: None,
+            coin: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn coin(mut self, coin: impl Into<String>) -> Self {
+        self.coin = Some(coin.into());
+        self
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SetAutoAddMarginParams {
+    /// Product type. linear (USDT Contract, USDC Contract)
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: String,
+    /// Turn on/off. 0: off. 1: on
+    #[serde(serialize_with = "bool_to_int")]
+    pub auto_add_margin: bool,
+    /// Used to identify positions in different position modes. For hedge mode position, this param is required
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_idx: Option<PositionIdx>,
+}
+
+impl SetAutoAddMarginParams {
+    pub fn new(category: Category, symbol: impl Into<String>, auto_add_margin: bool) -> Self {
+        Self {
+            category,
+            symbol: symbol.into(),
+            auto_add_margin,
+            position_idx: None,
+        }
+    }
+
+    pub fn position_idx(mut self, position_idx: PositionIdx) -> Self {
+        self.position_idx = Some(position_idx);
+        self
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetClosedPnlParams {
+    /// Product type
+    /// UTA2.0: linear, inverse, option
+    /// UTA1.0: linear, inverse
+    /// Classic: linear, inverse
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: Option<String>,
+    /// The start timestamp (ms)
+    /// startTime and endTime are not passed, return 7 days by default
+    /// Only startTime is passed, return range between startTime and startTime + 7 days
+    /// Only endTime is passed, return range between endTime-7 days and endTime
+    /// If both are passed, the rule is endTime - startTime <= 7 days
+    pub start_time: Option<Timestamp>,
+    /// The end timestamp (ms)
+    pub end_time: Option<Timestamp>,
+    /// Limit for data size per page. [1, 100]. Default: 50
+    pub limit: Option<u64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetClosedPnlParams {
+    pub fn new(category: Category) -> Self {
+        Self {
+            category,
+            symbol: None,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+
+    pub fn linear(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Linear).symbol(symbol)
+    }
+
+    pub fn inverse(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Inverse).symbol(symbol)
+    }
+
+    pub fn option(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Option).symbol(symbol)
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn start_time(mut self, start_time: Timestamp) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: Timestamp) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosedPnl {
+    /// Symbol name
+    pub symbol: String,
+    /// Order ID
+    pub order_id: String,
+    /// Buy, Sell
+    pub side: Side,
+    /// Order qty
+    pub qty: Decimal,
+    /// Order price
+    pub order_price: Decimal,
+    /// Order type. Market, Limit
+    pub order_type: OrderType,
+    /// Exec type
+    pub exec_type: ExecType,
+    /// Closed size
+    pub closed_size: Decimal,
+    /// Cumulated Position value
+    pub cum_entry_value: Decimal,
+    /// Average entry price
+    pub avg_entry_price: Decimal,
+    /// Cumulated exit position value
+    pub cum_exit_value: Decimal,
+    /// Average exit price
+    pub avg_exit_price: Decimal,
+    /// Closed PnL
+    pub closed_pnl: Decimal,
+    /// The number of fills in a single order
+    #[serde(deserialize_with = "number")]
+    pub fill_count: u64,
+    /// Leverage
+    pub leverage: Decimal,
+    /// Open position trading fee
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub open_fee: Option<Decimal>,
+    /// Close position trading fee
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub close_fee: Option<Decimal>,
+    /// The created time (ms)
+    #[serde(deserialize_with = "number")]
+    pub created_time: Timestamp,
+    /// The updated time (ms)
+    #[serde(deserialize_with = "number")]
+    pub updated_time: Timestamp,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetExecutionsParams {
+    /// Product type
+    /// UTA2.0, UTA1.0: linear, inverse, spot, option
+    /// Classic: linear, inverse, spot
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: Option<String>,
+    /// Order ID
+    pub order_id: Option<String>,
+    /// User customised order ID. Classic account does not support this param
+    pub order_link_id: Option<String>,
+    /// Base coin, uppercase only. UTA2.0, UTA1.0: linear, inverse, option. Classic: not supported
+    pub base_coin: Option<String>,
+    /// The start timestamp (ms)
+    /// startTime and endTime are not passed, return 7 days by default
+    /// Only startTime is passed, return range between startTime and startTime + 7 days
+    /// Only endTime is passed, return range between endTime-7 days and endTime
+    /// If both are passed, the rule is endTime - startTime <= 7 days
+    pub start_time: Option<Timestamp>,
+    /// The end timestamp (ms)
+    pub end_time: Option<Timestamp>,
+    /// Execution type. Classic spot is not supported
+    pub exec_type: Option<ExecType>,
+    /// Limit for data size per page. [1, 100]. Default: 50
+    pub limit: Option<u64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetExecutionsParams {
+    pub fn new(category: Category) -> Self {
+        Self {
+            category,
+            symbol: None,
+            order_id: None,
+            order_link_id: None,
+            base_coin: None,
+            start_time: None,
+            end_time: None,
+            exec_type: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+
+    pub fn spot(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Spot).symbol(symbol)
+    }
+
+    pub fn linear(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Linear).symbol(symbol)
+    }
+
+    pub fn inverse(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Inverse).symbol(symbol)
+    }
+
+    pub fn option(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Option).symbol(symbol)
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn order_id(mut self, order_id: impl Into<String>) -> Self {
+        self.order_id = Some(order_id.into());
+        self
+    }
+
+    pub fn order_link_id(mut self, order_link_id: impl Into<String>) -> Self {
+        self.order_link_id = Some(order_link_id.into());
+        self
+    }
+
+    pub fn base_coin(mut self, base_coin: impl Into<String>) -> Self {
+        self.base_coin = Some(base_coin.into());
+        self
+    }
+
+    pub fn start_time(mut self, start_time: Timestamp) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: Timestamp) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn exec_type(mut self, exec_type: ExecType) -> Self {
+        self.exec_type = Some(exec_type);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Execution {
+    /// Symbol name
+    pub symbol: String,
+    /// Order ID
+    pub order_id: String,
+    /// User customized order ID. Classic spot is not supported
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub order_link_id: Option<String>,
+    /// Side. Buy, Sell
+    pub side: Side,
+    /// Order price
+    pub order_price: Decimal,
+    /// Order qty
+    pub order_qty: Decimal,
+    /// The remaining qty not executed. Classic spot is not supported
+    pub leaves_qty: Decimal,
+    /// Order create type
+    /// Only for category=linear or inverse
+    /// Spot, Option do not have this key
+    #[serde(default, deserialize_with = "invalid_as_none")]
+    pub create_type: Option<CreateType>,
+    /// Order type. Market, Limit
+    pub order_type: OrderType,
+    /// Stop order type. If the order is not stop order, it either returns UNKNOWN or "". Classic spot is not supported
+    #[serde(default, deserialize_with = "invalid_as_none")]
+    pub stop_order_type: Option<StopOrderType>,
+    /// Executed trading fee. You can get spot fee currency instruction here. Classic spot is not supported
+    pub exec_fee: Decimal,
+    /// Execution ID
+    pub exec_id: String,
+    /// Execution price
+    pub exec_price: Decimal,
+    /// Execution qty
+    pub exec_qty: Decimal,
+    /// Executed type. Classic spot is not supported
+    pub exec_type: ExecType,
+    /// Executed order value. Classic spot is not supported
+    pub exec_value: Decimal,
+    /// Executed timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub exec_time: Timestamp,
+    /// Spot trading fee currency. Classic spot is not supported
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub fee_currency: Option<String>,
+    /// Is maker order. true: maker, false: taker
+    pub is_maker: bool,
+    /// Trading fee rate. Classic spot is not supported
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub fee_rate: Option<Decimal>,
+    /// Implied volatility. Valid for option
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub trade_iv: Option<Decimal>,
+    /// Implied volatility of mark price. Valid for option
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub mark_iv: Option<Decimal>,
+    /// The mark price of the symbol when executing. Classic spot is not supported
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub mark_price: Option<Decimal>,
+    /// The index price of the symbol when executing. Valid for option only
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub index_price: Option<Decimal>,
+    /// The underlying price of the symbol when executing. Valid for option
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub underlying_price: Option<Decimal>,
+    /// Paradigm block trade ID
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub block_trade_id: Option<String>,
+    /// Closed position size
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub closed_size: Option<Decimal>,
+    /// Cross sequence, used to associate each fill and each position update
+    pub seq: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetWalletBalanceParams {
+    /// Account type
+    /// UTA2.0: UNIFIED
+    /// UTA1.0: UNIFIED (trade spot/linear/options), CONTRACT(trade inverse)
+    /// Classic: CONTRACT, SPOT
+    pub account_type: AccountType,
+    /// Coin name, uppercase only
+    /// If not passed, it returns non-zero asset info
+    /// You can pass multiple coins to query, separated by comma. USDT,USDC
+    pub coin: Option<String>,
+}
+
+impl GetWalletBalanceParams {
+    pub fn new(account_type: AccountType) -> Self {
+        Self {
+            account_type,
+            coin: None,
+        }
+    }
+
+    pub fn coin(mut self, coin: impl Into<String>) -> Self {
+        self.coin = Some(coin.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountWallet {
+    /// Account type
+    pub account_type: AccountType,
+    /// Account LTV: account total borrowed size / (account total equity + account total borrowed size). Non-UTA: ""
+    #[serde(rename = "accountLTV", default, deserialize_with = "option_decimal")]
+    pub account_ltv: Option<Decimal>,
+    /// Account IM rate. Non-UTA: ""
+    #[serde(rename = "accountIMRate", default, deserialize_with = "option_decimal")]
+    pub account_im_rate: Option<Decimal>,
+    /// Account MM rate. Non-UTA: ""
+    #[serde(rename = "accountMMRate", default, deserialize_with = "option_decimal")]
+    pub account_mm_rate: Option<Decimal>,
+    /// Account total equity (USD): ∑Asset Equity By USD value of each asset
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub total_equity: Option<Decimal>,
+    /// Account wallet balance (USD): ∑Asset Wallet Balance By USD value of each asset
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub total_wallet_balance: Option<Decimal>,
+    /// Account margin balance (USD): totalWalletBalance + totalPerpUPL
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub total_margin_balance: Option<Decimal>,
+    /// Account available balance (USD), Cross Margin: totalMarginBalance - totalInitialMargin
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub total_available_balance: Option<Decimal>,
+    /// Account Perps and Futures unrealised p&l (USD): ∑Each Perp and USDC Futures upl by base coin
+    #[serde(rename = "totalPerpUPL", default, deserialize_with = "option_decimal")]
+    pub total_perp_upl: Option<Decimal>,
+    /// Account initial margin (USD): ∑Asset Total Initial Margin Base Coin
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub total_initial_margin: Option<Decimal>,
+    /// Account maintenance margin (USD): ∑ Asset Total Maintenance Margin Base Coin
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub total_maintenance_margin: Option<Decimal>,
+    /// Coin balances
+    pub coin: Vec<CoinBalance>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinBalance {
+    /// Coin name, such as BTC, ETH, USDT, USDC
+    pub coin: String,
+    /// Equity of coin. Asset Equity = Asset Wallet Balance + Asset Perp UPL + Asset Future UPL + Asset Option Value
+    pub equity: Decimal,
+    /// USD value of coin
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub usd_value: Option<Decimal>,
+    /// Wallet balance of coin
+    pub wallet_balance: Decimal,
+    /// Available balance for Spot wallet. This is a unique field for Classic SPOT
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub free: Option<Decimal>,
+    /// Locked balance due to the Spot open order
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub locked: Option<Decimal>,
+    /// The spot asset qty that is used to hedge in the portfolio margin, truncate to 8 decimals and "0" by default
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub spot_hedging_qty: Option<Decimal>,
+    /// Borrow amount of current coin
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub borrow_amount: Option<Decimal>,
+    /// Accrued interest
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub accrued_interest: Option<Decimal>,
+    /// Pre-occupied margin for order. For portfolio margin mode, it returns ""
+    #[serde(rename = "totalOrderIM", default, deserialize_with = "option_decimal")]
+    pub total_order_im: Option<Decimal>,
+    /// Sum of initial margin of all positions + Pre-occupied liquidation fee. For portfolio margin mode, it returns ""
+    #[serde(
+        rename = "totalPositionIM",
+        default,
+        deserialize_with = "option_decimal"
+    )]
+    pub total_position_im: Option<Decimal>,
+    /// Sum of maintenance margin for all positions. For portfolio margin mode, it returns ""
+    #[serde(
+        rename = "totalPositionMM",
+        default,
+        deserialize_with = "option_decimal"
+    )]
+    pub total_position_mm: Option<Decimal>,
+    /// Unrealised P&L
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub unrealised_pnl: Option<Decimal>,
+    /// Cumulative Realised P&L
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub cum_realised_pnl: Option<Decimal>,
+    /// Bonus. This is a unique field for accountType=UNIFIED
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub bonus: Option<Decimal>,
+    /// Whether it can be used as a margin collateral currency (platform)
+    #[serde(default)]
+    pub margin_collateral: Option<bool>,
+    /// Whether the collateral is turned on by user (user)
+    #[serde(default)]
+    pub collateral_switch: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfo {
+    /// Account status
+    pub unified_margin_status: UnifiedMarginStatus,
+    /// ISOLATED_MARGIN, REGULAR_MARGIN, PORTFOLIO_MARGIN
+    pub margin_mode: MarginMode,
+    /// Whether this account is a leader (copytrading). true, false
+    pub is_master_trader: bool,
+    /// Whether the unified account enables Spot hedging. ON, OFF
+    #[serde(deserialize_with = "on_off_to_bool")]
+    pub spot_hedging_status: bool,
+    /// Account data updated timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub updated_time: Timestamp,
+    /// deprecated, always "OFF". Please use Get DCP Info
+    #[serde(default, deserialize_with = "option_on_off_to_bool")]
+    pub dcp_status: Option<bool>,
+    /// deprecated, always 0. Please use Get DCP Info
+    #[serde(default)]
+    pub time_window: Option<u64>,
+    /// deprecated, always 0. Please query Get SMP Group ID endpoint
+    #[serde(default)]
+    pub smp_group: Option<i64>,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetTransactionLogParams {
+    /// Account Type. UNIFIED
+    pub account_type: Option<AccountType>,
+    /// Product type. spot, linear, option, inverse
+    pub category: Option<Category>,
+    /// Currency, uppercase only
+    pub currency: Option<String>,
+    /// BaseCoin, uppercase only. e.g., BTC of BTCPERP
+    pub base_coin: Option<String>,
+    /// Types of transaction logs
+    #[serde(rename = "type")]
+    pub transaction_type: Option<Type>,
+    /// The start timestamp (ms)
+    /// startTime and endTime are not passed, return 24 hours by default
+    /// Only startTime is passed, return range between startTime and startTime+24 hours
+    /// Only endTime is passed, return range between endTime-24 hours and endTime
+    /// If both are passed, the rule is endTime - startTime <= 7 days
+    pub start_time: Option<Timestamp>,
+    /// The end timestamp (ms)
+    pub end_time: Option<Timestamp>,
+    /// Limit for data size per page. [1, 50]. Default: 20
+    pub limit: Option<u64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetTransactionLogParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn account_type(mut self, account_type: AccountType) -> Self {
+        self.account_type = Some(account_type);
+        self
+    }
+
+    pub fn category(mut self, category: Category) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    pub fn currency(mut self, currency: impl Into<String>) -> Self {
+        self.currency = Some(currency.into());
+        self
+    }
+
+    pub fn base_coin(mut self, base_coin: impl Into<String>) -> Self {
+        self.base_coin = Some(base_coin.into());
+        self
+    }
+
+    pub fn transaction_type(mut self, transaction_type: Type) -> Self {
+        self.transaction_type = Some(transaction_type);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: Timestamp) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: Timestamp) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionLog {
+    /// Unique id
+    pub id: String,
+    /// Symbol name
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub symbol: Option<String>,
+    /// Product type
+    #[serde(default, deserialize_with = "invalid_as_none")]
+    pub category: Option<Category>,
+    /// Side. Buy, Sell, None
+    #[serde(default, deserialize_with = "invalid_as_none")]
+    pub side: Option<Side>,
+    /// Transaction timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub transaction_time: Timestamp,
+    /// Type
+    #[serde(rename = "type")]
+    pub transaction_type: Type,
+    /// Quantity
+    /// Spot: the negative means the qty of this currency is decreased, the positive means the qty of this currency is increased
+    /// Perps & Futures: it is the quantity for each trade entry and it does not have direction
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub qty: Option<Decimal>,
+    /// Size. The rest position size after the trade is executed, and it has direction, i.e., short with "-"
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub size: Option<Decimal>,
+    /// e.g., USDC, USDT, BTC, ETH
+    pub currency: String,
+    /// Trade price
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub trade_price: Option<Decimal>,
+    /// Funding fee
+    /// Positive fee value means an expense and negative fee value means a rebate
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub funding: Option<Decimal>,
+    /// Trading fee
+    /// Positive fee value means an expense and negative fee value means a rebate
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub fee: Option<Decimal>,
+    /// Cash flow, e.g., (1) close the position, and unRPL converts to RPL, (2) 8-hour session settlement for USDC Perp and Futures, (3) transferIn and transferOut. This does not include trading fee, funding fee
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub cash_flow: Option<Decimal>,
+    /// Change = cashFlow + funding - fee
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub change: Option<Decimal>,
+    /// Cash balance. This is the wallet balance after a cash change
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub cash_balance: Option<Decimal>,
+    /// When type=TRADE, then it is trading fee rate.
+    /// When type=SETTLEMENT, it means funding fee rate. For side=Buy, feeRate=market fee rate; For side=Sell, feeRate= - market fee rate
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub fee_rate: Option<Decimal>,
+    /// The change of bonus
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub bonus_change: Option<Decimal>,
+    /// Trade ID
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub trade_id: Option<String>,
+    /// Order ID
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub order_id: Option<String>,
+    /// User customised order ID
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub order_link_id: Option<String>,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetBorrowHistoryParams {
+    /// USDC, USDT, BTC, ETH etc, uppercase only
+    pub currency: Option<String>,
+    /// The start timestamp (ms)
+    /// startTime and endTime are not passed, return 30 days by default
+    /// If both are passed, the rule is endTime - startTime <= 30 days
+    pub start_time: Option<Timestamp>,
+    /// The end time. timestamp (ms)
+    pub end_time: Option<Timestamp>,
+    /// Limit for data size per page. [1, 50]. Default: 20
+    pub limit: Option<u64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetBorrowHistoryParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn currency(mut self, currency: impl Into<String>) -> Self {
+        self.currency = Some(currency.into());
+        self
+    }
+
+    pub fn start_time(mut self, start_time: Timestamp) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: Timestamp) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowHistory {
+    /// USDC, USDT, BTC, ETH
+    pub currency: String,
+    /// Created timestamp (ms)
+    pub created_time: Timestamp,
+    /// Interest
+    pub borrow_cost: Decimal,
+    /// Hourly Borrow Rate
+    pub hourly_borrow_rate: Decimal,
+    /// Interest Bearing Borrow Size
+    #[serde(rename = "InterestBearingBorrowSize")]
+    pub interest_bearing_borrow_size: Decimal,
+    /// Cost exemption
+    pub cost_exemption: Decimal,
+    /// Total borrow amount
+    pub borrow_amount: Decimal,
+    /// Unrealised loss
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub unrealised_loss: Option<Decimal>,
+    /// The borrowed amount for interest free
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub free_borrowed_amount: Option<Decimal>,
+}
+
+#[derive(Serialize, Default)]
+#[non_exhaustive]
+pub struct GetCollateralInfoParams {
+    /// Asset currency of all current collateral, uppercase only
+    pub currency: Option<String>,
+}
+
+impl GetCollateralInfoParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn currency(mut self, currency: impl Into<String>) -> Self {
+        self.currency = Some(currency.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralCoin {
+    /// Currency of all current collateral
+    pub currency: String,
+    /// Hourly borrow rate. "" when the coin is not borrowable
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub hourly_borrow_rate: Option<Decimal>,
+    /// Max borrow amount. This value is shared across main-sub UIDs
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub max_borrowing_amount: Option<Decimal>,
+    /// The maximum limit for interest-free borrowing
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub free_borrowing_limit: Option<Decimal>,
+    /// The amount of borrowing within your total borrowing amount that is exempt from interest charges
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub free_borrow_amount: Option<Decimal>,
+    /// Borrow amount
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub borrow_amount: Option<Decimal>,
+    /// The sum of borrowing amount for other accounts under the same main account
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub other_borrow_amount: Option<Decimal>,
+    /// Available amount to borrow. This value is shared across main-sub UIDs
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub available_to_borrow: Option<Decimal>,
+    /// Whether currency can be borrowed
+    pub borrowable: bool,
+    /// Borrow usage rate: sum of main & sub accounts borrowAmount/maxBorrowingAmount, it is an actual value, 0.5 means 50%
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub borrow_usage_rate: Option<Decimal>,
+    /// Whether it can be used as a margin collateral currency (platform), true: YES, false: NO
+    pub margin_collateral: bool,
+    /// Whether the collateral is turned on by user (user), true: ON, false: OFF
+    pub collateral_switch: bool,
+    /// Due to the new Tiered Collateral value logic, this field will no longer be accurate starting on February 19, 2025. Please refer to Get Tiered Collateral Ratio
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub collateral_ratio: Option<Decimal>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SetMarginModeParams {
+    /// ISOLATED_MARGIN, REGULAR_MARGIN(i.e. Cross margin), PORTFOLIO_MARGIN
+    pub set_margin_mode: MarginMode,
+}
+
+impl SetMarginModeParams {
+    pub fn new(set_margin_mode: MarginMode) -> Self {
+        Self { set_margin_mode }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct SetMarginModeResult {
+    /// Reasons why the margin mode could not be changed. Empty on success
+    pub reasons: Vec<MarginModeReason>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginModeReason {
+    /// Fail reason code
+    pub reason_code: String,
+    /// Fail reason msg
+    pub reason_msg: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UpgradeToUtaResult {
+    /// Upgrade status. FAIL, PROCESS, SUCCESS
+    pub unified_update_status: UnifiedUpdateStatus,
+    /// If PROCESS, SUCCESS, it returns null
+    pub unified_update_msg: Option<UnifiedUpdateMsg>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct UnifiedUpdateMsg {
+    /// Error message array. Only FAIL will have this field
+    pub msg: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RequestDemoFundsParams {
+    /// 0(default): add demo funds; 1: reduce demo funds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adjust_type: Option<DemoAdjustType>,
+    /// Coins and amounts to adjust
+    pub uta_demo_apply_money: Vec<DemoApplyMoney>,
+}
+
+impl RequestDemoFundsParams {
+    pub fn new(uta_demo_apply_money: Vec<DemoApplyMoney>) -> Self {
+        Self {
+            uta_demo_apply_money,
+            adjust_type: None,
+        }
+    }
+
+    pub fn adjust_type(mut self, adjust_type: DemoAdjustType) -> Self {
+        self.adjust_type = Some(adjust_type);
+        self
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemoApplyMoney {
+    /// Applied coin, supports BTC, ETH, USDT, USDC
+    pub coin: String,
+    /// Applied amount, the max applied amount in each request
+    /// BTC: "15"
+    /// ETH: "200"
+    /// USDT: "100000"
+    /// USDC: "100000"
+    pub amount_str: Decimal,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetFeeRatesParams {
+    /// Product type. spot, linear, inverse, option
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only. Valid for linear, inverse, spot
+    pub symbol: Option<String>,
+    /// Base coin, uppercase only. SOL, BTC, ETH. Valid for option
+    pub base_coin: Option<String>,
+}
+
+impl GetFeeRatesParams {
+    pub fn new(category: Category) -> Self {
+        Self {
+            category,
+            symbol: None,
+            base_coin: None,
+        }
+    }
+
+    pub fn spot(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Spot).symbol(symbol)
+    }
+
+    pub fn linear(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Linear).symbol(symbol)
+    }
+
+    pub fn inverse(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Inverse).symbol(symbol)
+    }
+
+    /// Option fee rates are per base coin, the symbol is ignored.
+    pub fn option(base_coin: impl Into<String>) -> Self {
+        Self::new(Category::Option).base_coin(base_coin)
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn base_coin(mut self, base_coin: impl Into<String>) -> Self {
+        self.base_coin = Some(base_coin.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeRate {
+    /// Symbol name. Keeps "" for Options
+    #[serde(default)]
+    pub symbol: String,
+    /// Base coin. SOL, BTC, ETH. Keeps "" for Spot, Perpetual and Futures
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub base_coin: Option<String>,
+    /// Taker fee rate
+    pub taker_fee_rate: Decimal,
+    /// Maker fee rate
+    pub maker_fee_rate: Decimal,
+}
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct InterTransferParams {
+    /// UUID. Please manually generate a UUID. Reuse it to retry a transfer without duplicating it
+    pub transfer_id: String,
+    /// Coin, uppercase only
+    pub coin: String,
+    /// Amount
+    pub amount: Decimal,
+    /// From account type
+    pub from_account_type: AccountType,
+    /// To account type
+    pub to_account_type: AccountType,
+}
+
+impl InterTransferParams {
+    /// Create params with a newly generated transferId.
+    pub fn new(
+        coin: impl Into<String>,
+        amount: Decimal,
+        from_account_type: AccountType,
+        to_account_type: AccountType,
+    ) -> Self {
+        Self {
+            transfer_id: Uuid::new_v4().to_string(),
+            coin: coin.into(),
+            amount,
+            from_account_type,
+            to_account_type,
+        }
+    }
+
+    /// Reuse the transferId of an earlier attempt to retry it without duplicating the transfer.
+    pub fn transfer_id(mut self, transfer_id: impl Into<String>) -> Self {
+        self.transfer_id = transfer_id.into();
+        self
+    }
+}
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UniversalTransferParams {
+    /// UUID. Please manually generate a UUID. Reuse it to retry a transfer without duplicating it
+    pub transfer_id: String,
+    /// Coin, uppercase only
+    pub coin: String,
+    /// Amount
+    pub amount: Decimal,
+    /// From UID
+    pub from_member_id: u64,
+    /// To UID
+    pub to_member_id: u64,
+    /// From account type
+    pub from_account_type: AccountType,
+    /// To account type
+    pub to_account_type: AccountType,
+}
+
+impl UniversalTransferParams {
+    /// Create params with a newly generated transferId.
+    pub fn new(
+        coin: impl Into<String>,
+        amount: Decimal,
+        from_member_id: u64,
+        to_member_id: u64,
+        from_account_type: AccountType,
+        to_account_type: AccountType,
+    ) -> Self {
+        Self {
+            transfer_id: Uuid::new_v4().to_string(),
+            coin: coin.into(),
+            amount,
+            from_member_id,
+            to_member_id,
+            from_account_type,
+            to_account_type,
+        }
+    }
+
+    /// Reuse the transferId of an earlier attempt to retry it without duplicating the transfer.
+    pub fn transfer_id(mut self, transfer_id: impl Into<String>) -> Self {
+        self.transfer_id = transfer_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferResult {
+    /// UUID
+    pub transfer_id: String,
+    /// Transfer status. STATUS_UNKNOWN, SUCCESS, PENDING, FAILED
+    #[serde(default, deserialize_with = "invalid_as_none")]
+    pub status: Option<TransferStatus>,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetTransferRecordsParams {
+    /// UUID. Use the one you generated in createTransfer
+    pub transfer_id: Option<String>,
+    /// Coin, uppercase only
+    pub coin: Option<String>,
+    /// Transfer status
+    pub status: Option<TransferStatus>,
+    /// The start timestamp (ms)
+    /// startTime and endTime are not passed, return 7 days by default
+    /// Only startTime is passed, return range between startTime and startTime+7 days
+    /// Only endTime is passed, return range between endTime-7 days and endTime
+    /// If both are passed, the rule is endTime - startTime <= 7 days
+    pub start_time: Option<Timestamp>,
+    /// The end time. timestamp (ms)
+    pub end_time: Option<Timestamp>,
+    /// Limit for data size per page. [1, 50]. Default: 20
+    pub limit: Option<u64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetTransferRecordsParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn transfer_id(mut self, transfer_id: impl Into<String>) -> Self {
+        self.transfer_id = Some(transfer_id.into());
+        self
+    }
+
+    pub fn coin(mut self, coin: impl Into<String>) -> Self {
+        self.coin = Some(coin.into());
+        self
+    }
+
+    pub fn status(mut self, status: TransferStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: Timestamp) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: Timestamp) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InterTransferRecord {
+    /// Transfer ID
+    pub transfer_id: String,
+    /// Transferred coin
+    pub coin: String,
+    /// Transferred amount
+    pub amount: Decimal,
+    /// From account type
+    pub from_account_type: AccountType,
+    /// To account type
+    pub to_account_type: AccountType,
+    /// Transfer created timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub timestamp: Timestamp,
+    /// Transfer status
+    pub status: TransferStatus,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferRecord {
+    /// Transfer ID
+    pub transfer_id: String,
+    /// Transferred coin
+    pub coin: String,
+    /// Transferred amount
+    pub amount: Decimal,
+    /// From UID
+    #[serde(deserialize_with = "number")]
+    pub from_member_id: u64,
+    /// To UID
+    #[serde(deserialize_with = "number")]
+    pub to_member_id: u64,
+    /// From account type
+    pub from_account_type: AccountType,
+    /// To account type
+    pub to_account_type: AccountType,
+    /// Transfer created timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub timestamp: Timestamp,
+    /// Transfer status
+    pub status: TransferStatus,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetCoinBalanceParams {
+    /// UID. Required when querying sub UID balance with master api key
+    pub member_id: Option<String>,
+    /// UID. Required when querying the transferable balance between different UIDs
+    pub to_member_id: Option<String>,
+    /// Account type
+    pub account_type: AccountType,
+    /// To account type. Required when querying the transferable balance between different account types
+    pub to_account_type: Option<AccountType>,
+    /// Coin, uppercase only
+    pub coin: String,
+    /// Whether query bonus or not. Default: false
+    #[serde(serialize_with = "option_bool_to_int")]
+    pub with_bonus: Option<bool>,
+    /// Whether query delay withdraw/transfer safe amount. Default: false
+    #[serde(serialize_with = "option_bool_to_int")]
+    pub with_transfer_safe_amount: Option<bool>,
+    /// For OTC loan users in particular, you can check the transferable amount under risk level. Default: false
+    #[serde(serialize_with = "option_bool_to_int")]
+    pub with_ltv_transfer_safe_amount: Option<bool>,
+}
+
+impl GetCoinBalanceParams {
+    pub fn new(account_type: AccountType, coin: impl Into<String>) -> Self {
+        Self {
+            account_type,
+            coin: coin.into(),
+            member_id: None,
+            to_member_id: None,
+            to_account_type: None,
+            with_bonus: None,
+            with_transfer_safe_amount: None,
+            with_ltv_transfer_safe_amount: None,
+        }
+    }
+
+    pub fn member_id(mut self, member_id: impl Into<String>) -> Self {
+        self.member_id = Some(member_id.into());
+        self
+    }
+
+    pub fn to_member_id(mut self, to_member_id: impl Into<String>) -> Self {
+        self.to_member_id = Some(to_member_id.into());
+        self
+    }
+
+    pub fn to_account_type(mut self, to_account_type: AccountType) -> Self {
+        self.to_account_type = Some(to_account_type);
+        self
+    }
+
+    pub fn with_bonus(mut self, with_bonus: bool) -> Self {
+        self.with_bonus = Some(with_bonus);
+        self
+    }
+
+    pub fn with_transfer_safe_amount(mut self, with_transfer_safe_amount: bool) -> Self {
+        self.with_transfer_safe_amount = Some(with_transfer_safe_amount);
+        self
+    }
+
+    pub fn with_ltv_transfer_safe_amount(mut self, with_ltv_transfer_safe_amount: bool) -> Self {
+        self.with_ltv_transfer_safe_amount = Some(with_ltv_transfer_safe_amount);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountCoinBalance {
+    /// Account type
+    pub account_type: AccountType,
+    /// Biz type
+    pub biz_type: i64,
+    /// Account ID
+    pub account_id: String,
+    /// Uid
+    pub member_id: String,
+    /// Coin balance
+    pub balance: TransferCoinBalance,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferCoinBalance {
+    /// Coin
+    pub coin: String,
+    /// Wallet balance
+    pub wallet_balance: Decimal,
+    /// Transferable balance
+    pub transfer_balance: Decimal,
+    /// Bonus
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub bonus: Option<Decimal>,
+    /// Safe amount to transfer. Keep "" if not query
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub transfer_safe_amount: Option<Decimal>,
+    /// Transferable amount for ins loan account. Keep "" if not query
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub ltv_transfer_safe_amount: Option<Decimal>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetAllCoinsBalanceParams {
+    /// User Id. It is required when you use master api key to check sub account coin balance
+    pub member_id: Option<String>,
+    /// Account type
+    pub account_type: AccountType,
+    /// Coin name, uppercase only. Query all coins if not passed. Can query multiple coins, separated by comma. USDT,USDC,ETH
+    pub coin: Option<String>,
+    /// Whether query bonus or not. Default: false
+    #[serde(serialize_with = "option_bool_to_int")]
+    pub with_bonus: Option<bool>,
+}
+
+impl GetAllCoinsBalanceParams {
+    pub fn new(account_type: AccountType) -> Self {
+        Self {
+            account_type,
+            member_id: None,
+            coin: None,
+            with_bonus: None,
+        }
+    }
+
+    pub fn member_id(mut self, member_id: impl Into<String>) -> Self {
+        self.member_id = Some(member_id.into());
+        self
+    }
+
+    pub fn coin(mut self, coin: impl Into<String>) -> Self {
+        self.coin = Some(coin.into());
+        self
+    }
+
+    pub fn with_bonus(mut self, with_bonus: bool) -> Self {
+        self.with_bonus = Some(with_bonus);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AllCoinsBalance {
+    /// Account type
+    pub account_type: AccountType,
+    /// UserID
+    pub member_id: String,
+    /// Coin balances
+    pub balance: Vec<TransferCoinBalance>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetAssetInfoParams {
+    /// Account type. SPOT
+    pub account_type: AccountType,
+    /// Coin name, uppercase only
+    pub coin: Option<String>,
+}
+
+impl GetAssetInfoParams {
+    pub fn new(account_type: AccountType) -> Self {
+        Self {
+            account_type,
+            coin: None,
+        }
+    }
+
+    pub fn coin(mut self, coin: impl Into<String>) -> Self {
+        self.coin = Some(coin.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetInfo {
+    /// Spot account assets
+    pub spot: SpotAssetInfo,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotAssetInfo {
+    /// Account status. ACCOUNT_STATUS_NORMAL, ACCOUNT_STATUS_UNSPECIFIED
+    pub status: String,
+    /// Assets
+    pub assets: Vec<SpotAsset>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotAsset {
+    /// Coin
+    pub coin: String,
+    /// Freeze amount
+    pub frozen: Decimal,
+    /// Free balance
+    pub free: Decimal,
+    /// Amount in withdrawing
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub withdraw: Option<Decimal>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetTransferableCoinsParams {
+    /// From account type
+    pub from_account_type: AccountType,
+    /// To account type
+    pub to_account_type: AccountType,
+}
+
+impl GetTransferableCoinsParams {
+    pub fn new(from_account_type: AccountType, to_account_type: AccountType) -> Self {
+        Self {
+            from_account_type,
+            to_account_type,
+        }
+    }
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetDepositRecordsParams {
+    /// Internal ID: Can be used to uniquely identify and filter the deposit. When combined with other parameters, this field takes the highest priority
+    pub id: Option<String>,
+    /// Transaction ID: Please note that data generated before Jan 1, 2024 cannot be queried using txID
+    #[serde(rename = "txID")]
+    pub tx_id: Option<String>,
+    /// Coin, uppercase only
+    pub coin: Option<String>,
+    /// The start timestamp (ms)
+    /// startTime and endTime are not passed, return 30 days by default
+    /// Only startTime is passed, return range between startTime and startTime + 30 days
+    /// Only endTime is passed, return range between endTime-30 days and endTime
+    /// If both are passed, the rule is endTime - startTime <= 30 days
+    pub start_time: Option<Timestamp>,
+    /// The end timestamp (ms)
+    pub end_time: Option<Timestamp>,
+    /// Limit for data size per page. [1, 50]. Default: 50
+    pub limit: Option<u64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetDepositRecordsParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn tx_id(mut self, tx_id: impl Into<String>) -> Self {
+        self.tx_id = Some(tx_id.into());
+        self
+    }
+
+    pub fn coin(mut self, coin: impl Into<String>) -> Self {
+        self.coin = Some(coin.into());
+        self
+    }
+
+    pub fn start_time(mut self, start_time: Timestamp) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: Timestamp) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetSubDepositRecordsParams {
+    /// Internal ID: Can be used to uniquely identify and filter the deposit. When combined with other parameters, this field takes the highest priority
+    pub id: Option<String>,
+    /// Transaction ID: Please note that data generated before Jan 1, 2024 cannot be queried using txID
+    #[serde(rename = "txID")]
+    pub tx_id: Option<String>,
+    /// Sub UID
+    pub sub_member_id: String,
+    /// Coin, uppercase only
+    pub coin: Option<String>,
+    /// The start timestamp (ms)
+    pub start_time: Option<Timestamp>,
+    /// The end timestamp (ms)
+    pub end_time: Option<Timestamp>,
+    /// Limit for data size per page. [1, 50]. Default: 50
+    pub limit: Option<u64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetSubDepositRecordsParams {
+    pub fn new(sub_member_id: impl Into<String>) -> Self {
+        Self {
+            sub_member_id: sub_member_id.into(),
+            id: None,
+            tx_id: None,
+            coin: None,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn tx_id(mut self, tx_id: impl Into<String>) -> Self {
+        self.tx_id = Some(tx_id.into());
+        self
+    }
+
+    pub fn coin(mut self, coin: impl Into<String>) -> Self {
+        self.coin = Some(coin.into());
+        self
+    }
+
+    pub fn start_time(mut self, start_time: Timestamp) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: Timestamp) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecords {
+    /// Refer to the cursor request parameter
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub next_page_cursor: Option<String>,
+    /// Deposit records
+    pub rows: Vec<DepositRecord>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    /// Unique ID
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub id: Option<String>,
+    /// Coin
+    pub coin: String,
+    /// Chain
+    pub chain: String,
+    /// Amount
+    pub amount: Decimal,
+    /// Transaction ID
+    #[serde(rename = "txID")]
+    pub tx_id: String,
+    /// Deposit status
+    pub status: DepositStatus,
+    /// Deposit target address
+    pub to_address: String,
+    /// Tag of deposit target address
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub tag: Option<String>,
+    /// Deposit fee
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub deposit_fee: Option<Decimal>,
+    /// Last updated time (ms)
+    #[serde(default, deserialize_with = "option_number")]
+    pub success_at: Option<Timestamp>,
+    /// Number of confirmation blocks
+    #[serde(deserialize_with = "number")]
+    pub confirmations: u64,
+    /// Transaction sequence number
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub tx_index: Option<String>,
+    /// Hash number on the chain
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub block_hash: Option<String>,
+    /// The deposit limit for this coin in this chain. "-1" means no limit
+    pub batch_release_limit: Decimal,
+    /// The deposit type. 0: normal deposit, 10: the deposit reaches daily deposit limit, 20: abnormal deposit
+    #[serde(deserialize_with = "number")]
+    pub deposit_type: u8,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetDepositAddressParams {
+    /// Coin, uppercase only
+    pub coin: String,
+    /// Please use the value of chain from coin-info endpoint
+    pub chain_type: Option<String>,
+}
+
+impl GetDepositAddressParams {
+    pub fn new(coin: impl Into<String>) -> Self {
+        Self {
+            coin: coin.into(),
+            chain_type: None,
+        }
+    }
+
+    pub fn chain_type(mut self, chain_type: impl Into<String>) -> Self {
+        self.chain_type = Some(chain_type.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddresses {
+    /// Coin
+    pub coin: String,
+    /// Chains
+    pub chains: Vec<DepositAddress>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetSubDepositAddressParams {
+    /// Coin, uppercase only
+    pub coin: String,
+    /// Please use the value of chain from coin-info endpoint
+    pub chain_type: String,
+    /// Sub user ID
+    pub sub_member_id: String,
+}
+
+impl GetSubDepositAddressParams {
+    pub fn new(
+        coin: impl Into<String>,
+        chain_type: impl Into<String>,
+        sub_member_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            coin: coin.into(),
+            chain_type: chain_type.into(),
+            sub_member_id: sub_member_id.into(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubDepositAddress {
+    /// Coin
+    pub coin: String,
+    /// Chain
+    pub chains: DepositAddress,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddress {
+    /// Chain type
+    pub chain_type: String,
+    /// The address for deposit
+    pub address_deposit: String,
+    /// Tag of deposit
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub tag_deposit: Option<String>,
+    /// Chain
+    pub chain: String,
+    /// The deposit limit for this coin in this chain. "-1" means no limit
+    pub batch_release_limit: Decimal,
+    /// The contract address of the coin. Only display last 6 characters, if there is no contract address, it shows ""
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub contract_address: Option<String>,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetAllowedDepositCoinsParams {
+    /// Coin, uppercase only
+    pub coin: Option<String>,
+    /// Chain
+    pub chain: Option<String>,
+    /// Limit for data size per page. [1, 35]. Default: 10
+    pub limit: Option<u64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetAllowedDepositCoinsParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn coin(mut self, coin: impl Into<String>) -> Self {
+        self.coin = Some(coin.into());
+        self
+    }
+
+    pub fn chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain = Some(chain.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AllowedDepositCoins {
+    /// Refer to the cursor request parameter
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub next_page_cursor: Option<String>,
+    /// Allowed deposit coins
+    pub config_list: Vec<AllowedDepositCoin>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AllowedDepositCoin {
+    /// Coin
+    pub coin: String,
+    /// Chain
+    pub chain: String,
+    /// Coin name
+    pub coin_show_name: String,
+    /// Chain type
+    pub chain_type: String,
+    /// Number of confirmations for deposit
+    #[serde(deserialize_with = "number")]
+    pub block_confirm_number: u64,
+    /// Minimum deposit amount
+    pub min_deposit_amount: Decimal,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetCoinInfoParams {
+    /// Coin, uppercase only
+    pub coin: Option<String>,
+}
+
+impl GetCoinInfoParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn coin(mut self, coin: impl Into<String>) -> Self {
+        self.coin = Some(coin.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinsInfo {
+    pub rows: Vec<CoinInfo>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinInfo {
+    /// Coin name
+    pub name: String,
+    /// Coin
+    pub coin: String,
+    /// Maximum withdraw amount per transaction
+    pub remain_amount: Decimal,
+    /// Chains
+    pub chains: Vec<ChainInfo>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainInfo {
+    /// Chain
+    pub chain: String,
+    /// Chain type
+    pub chain_type: String,
+    /// Number of confirmations for deposit: Once this number is reached, your funds will be credited to your account and available for trading
+    #[serde(deserialize_with = "number")]
+    pub confirmation: u64,
+    /// Withdraw fee. If withdraw fee is empty, It means that this coin does not support withdrawal
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub withdraw_fee: Option<Decimal>,
+    /// Min. deposit
+    pub deposit_min: Decimal,
+    /// Min. withdraw
+    pub withdraw_min: Decimal,
+    /// The precision of withdraw or deposit
+    #[serde(deserialize_with = "number")]
+    pub min_accuracy: u32,
+    /// The chain status of deposit. 0: suspend. 1: normal
+    #[serde(deserialize_with = "string_to_bool")]
+    pub chain_deposit: bool,
+    /// The chain status of withdraw. 0: suspend. 1: normal
+    #[serde(deserialize_with = "string_to_bool")]
+    pub chain_withdraw: bool,
+    /// The withdraw fee percentage. It is a real figure, e.g., 0.022 means 2.2%
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub withdraw_percentage_fee: Option<Decimal>,
+    /// Contract address. "" means no contract address
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub contract_address: Option<String>,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetWithdrawalRecordsParams {
+    /// Withdraw ID
+    #[serde(rename = "withdrawID")]
+    pub withdraw_id: Option<String>,
+    /// Transaction hash ID
+    #[serde(rename = "txID")]
+    pub tx_id: Option<String>,
+    /// Coin, uppercase only
+    pub coin: Option<String>,
+    /// Withdraw type. 0(default): on chain. 1: off chain. 2: all
+    pub withdraw_type: Option<WithdrawType>,
+    /// The start timestamp (ms)
+    /// startTime and endTime are not passed, return 30 days by default
+    /// If both are passed, the rule is endTime - startTime <= 30 days
+    pub start_time: Option<Timestamp>,
+    /// The end timestamp (ms)
+    pub end_time: Option<Timestamp>,
+    /// Limit for data size per page. [1, 50]. Default: 50
+    pub limit: Option<u64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetWithdrawalRecordsParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn withdraw_id(mut self, withdraw_id: impl Into<String>) -> Self {
+        self.withdraw_id = Some(withdraw_id.into());
+        self
+    }
+
+    pub fn tx_id(mut self, tx_id: impl Into<String>) -> Self {
+        self.tx_id = Some(tx_id.into());
+        self
+    }
+
+    pub fn coin(mut self, coin: impl Into<String>) -> Self {
+        self.coin = Some(coin.into());
+        self
+    }
+
+    pub fn withdraw_type(mut self, withdraw_type: WithdrawType) -> Self {
+        self.withdraw_type = Some(withdraw_type);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: Timestamp) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: Timestamp) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalRecords {
+    /// Refer to the cursor request parameter
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub next_page_cursor: Option<String>,
+    /// Withdrawal records
+    pub rows: Vec<WithdrawalRecord>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalRecord {
+    /// Withdraw ID
+    pub withdraw_id: String,
+    /// Transaction ID. It returns "" when withdrawal failed, withdrawal cancelled
+    #[serde(rename = "txID", default, deserialize_with = "empty_string_as_none")]
+    pub tx_id: Option<String>,
+    /// Withdraw type. 0: on chain. 1: off chain
+    pub withdraw_type: WithdrawType,
+    /// Coin
+    pub coin: String,
+    /// Chain
+    pub chain: String,
+    /// Amount
+    pub amount: Decimal,
+    /// Withdraw fee
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub withdraw_fee: Option<Decimal>,
+    /// Withdraw status
+    pub status: WithdrawStatus,
+    /// To withdrawal address. Shows the Bybit UID for internal transfer
+    pub to_address: String,
+    /// Tag
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub tag: Option<String>,
+    /// Withdraw created timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub create_time: Timestamp,
+    /// Withdraw updated timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub update_time: Timestamp,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CreateWithdrawalParams {
+    /// Coin, uppercase only
+    pub coin: String,
+    /// Chain
+    /// forceChain=0 or 1: this field is required
+    /// forceChain=2: this field can be null
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain: Option<String>,
+    /// forceChain=0 or 1: fill wallet address, and make sure you add address in the address book first
+    /// forceChain=2: fill Bybit UID, and it can only be another Bybit main account UID
+    pub address: String,
+    /// Tag. Required if tag exists in the wallet address list
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// Withdraw amount
+    pub amount: Decimal,
+    /// Current timestamp (ms). Used for preventing from withdraw replay
+    pub timestamp: Timestamp,
+    /// Whether or not to force an on-chain withdrawal. 0(default): If the address is parsed out to be an internal address, then internal transfer. 1: Force the withdrawal to occur on-chain. 2: Use UID to withdraw
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_chain: Option<u8>,
+    /// Select the wallet to be withdrawn from. SPOT, FUND, UTA, FUND,UTA
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<WithdrawAccountType>,
+    /// Handling fee option. 0(default): input amount is the actual amount received, so you have to calculate handling fee manually. 1: input amount is not the actual amount you received, the system will help to deduct the handling fee automatically
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_type: Option<u8>,
+    /// Customised ID, globally unique, it is used for idempotent verification. A combination of letters (case sensitive) and numbers, which can be pure letters or pure numbers and the length must be between 1 and 32 digits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+impl CreateWithdrawalParams {
+    /// Create params stamped with the current time.
+    pub fn new(coin: impl Into<String>, address: impl Into<String>, amount: Decimal) -> Self {
+        Self {
+            coin: coin.into(),
+            chain: None,
+            address: address.into(),
+            tag: None,
+            amount,
+            timestamp: timestamp() as Timestamp,
+            force_chain: None,
+            account_type: None,
+            fee_type: None,
+            request_id: None,
+        }
+    }
+
+    pub fn chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain = Some(chain.into());
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    pub fn force_chain(mut self, force_chain: u8) -> Self {
+        self.force_chain = Some(force_chain);
+        self
+    }
+
+    pub fn account_type(mut self, account_type: WithdrawAccountType) -> Self {
+        self.account_type = Some(account_type);
+        self
+    }
+
+    pub fn fee_type(mut self, fee_type: u8) -> Self {
+        self.fee_type = Some(fee_type);
+        self
+    }
+
+    pub fn request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalId {
+    /// Withdrawal ID
+    pub id: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CancelWithdrawalParams {
+    /// Withdrawal ID
+    pub id: String,
+}
+
+impl CancelWithdrawalParams {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelWithdrawalResult {
+    /// 0: fail. 1: success
+    #[serde(deserialize_with = "int_to_bool")]
+    pub status: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetDeliveryRecordsParams {
+    /// Product type. inverse(inverse futures), linear(USDT/USDC futures), option
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: Option<String>,
+    /// The start timestamp (ms)
+    /// startTime and endTime are not passed, return 30 days by default
+    /// Only startTime is passed, return range between startTime and startTime + 30 days
+    /// Only endTime is passed, return range between endTime-30 days and endTime
+    /// If both are passed, the rule is endTime - startTime <= 30 days
+    pub start_time: Option<Timestamp>,
+    /// The end timestamp (ms)
+    pub end_time: Option<Timestamp>,
+    /// Expiry date. 25MAR22. Default: return all
+    pub exp_date: Option<String>,
+    /// Limit for data size per page. [1, 50]. Default: 20
+    pub limit: Option<u64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetDeliveryRecordsParams {
+    pub fn new(category: Category) -> Self {
+        Self {
+            category,
+            symbol: None,
+            start_time: None,
+            end_time: None,
+            exp_date: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+
+    pub fn linear(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Linear).symbol(symbol)
+    }
+
+    pub fn inverse(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Inverse).symbol(symbol)
+    }
+
+    pub fn option(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Option).symbol(symbol)
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn start_time(mut self, start_time: Timestamp) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: Timestamp) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn exp_date(mut self, exp_date: impl Into<String>) -> Self {
+        self.exp_date = Some(exp_date.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryRecord {
+    /// Delivery time (ms)
+    #[serde(deserialize_with = "number")]
+    pub delivery_time: Timestamp,
+    /// Symbol name
+    pub symbol: String,
+    /// Buy,Sell
+    pub side: Side,
+    /// Executed size
+    pub position: Decimal,
+    /// Avg entry price
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub entry_price: Option<Decimal>,
+    /// Delivery price
+    pub delivery_price: Decimal,
+    /// Exercise price. Option only
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub strike: Option<Decimal>,
+    /// Trading fee
+    pub fee: Decimal,
+    /// Realized PnL of the delivery
+    pub delivery_rpl: Decimal,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetSettlementRecordsParams {
+    /// Product type. linear(USDC contract)
+    pub category: Category,
+    /// Symbol name, like BTCPERP, uppercase only
+    pub symbol: Option<String>,
+    /// The start timestamp (ms)
+    /// startTime and endTime are not passed, return 30 days by default
+    /// If both are passed, the rule is endTime - startTime <= 30 days
+    pub start_time: Option<Timestamp>,
+    /// The end time. timestamp (ms)
+    pub end_time: Option<Timestamp>,
+    /// Limit for data size per page. [1, 50]. Default: 20
+    pub limit: Option<u64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetSettlementRecordsParams {
+    pub fn new(category: Category) -> Self {
+        Self {
+            category,
+            symbol: None,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+
+    pub fn linear(symbol: impl Into<String>) -> Self {
+        Self::new(Category::Linear).symbol(symbol)
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn start_time(mut self, start_time: Timestamp) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: Timestamp) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementRecord {
+    /// Symbol name
+    pub symbol: String,
+    /// Buy,Sell
+    pub side: Side,
+    /// Position size
+    pub size: Decimal,
+    /// Settlement price
+    pub session_avg_price: Decimal,
+    /// Mark price
+    pub mark_price: Decimal,
+    /// Realised PnL
+    pub realised_pnl: Decimal,
+    /// Created time (ms)
+    #[serde(deserialize_with = "number")]
+    pub created_time: Timestamp,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubMemberTransferList {
+    /// All sub UIDs under the main UID
+    pub sub_member_ids: Vec<String>,
+    /// All sub UIDs that have universal transfer enabled
+    pub transferable_sub_member_ids: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct EnableSubMemberTransferParams {
+    /// This list has a single item. Separate multiple UIDs by comma, e.g., "uid1,uid2,uid3"
+    pub sub_member_ids: Vec<String>,
+}
+
+impl EnableSubMemberTransferParams {
+    pub fn new(sub_member_ids: Vec<String>) -> Self {
+        Self { sub_member_ids }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CreateSubMemberParams {
+    /// Give a username of the new sub user id. 6-16 characters, must include both numbers and letters. Cannot be the same as the exist or deleted one
+    pub username: String,
+    /// Set the password for the new sub user id. 8-30 characters, must include numbers, upper and lowercase letters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<SensitiveString>,
+    /// 1: normal sub account, 6: custodial sub account
+    pub member_type: SubMemberType,
+    /// Quick login. Default: false
+    #[serde(
+        rename = "switch",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_bool_to_int"
+    )]
+    pub quick_login: Option<bool>,
+    /// Whether to create the sub account as a unified trading account
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_uta: Option<bool>,
+    /// Set a remark
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl CreateSubMemberParams {
+    pub fn new(username: impl Into<String>, member_type: SubMemberType) -> Self {
+        Self {
+            username: username.into(),
+            member_type,
+            password: None,
+            quick_login: None,
+            is_uta: None,
+            note: None,
+        }
+    }
+
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(SensitiveString::from(password.into()));
+        self
+    }
+
+    pub fn quick_login(mut self, quick_login: bool) -> Self {
+        self.quick_login = Some(quick_login);
+        self
+    }
+
+    pub fn is_uta(mut self, is_uta: bool) -> Self {
+        self.is_uta = Some(is_uta);
+        self
+    }
+
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubMember {
+    /// Sub user Id
+    pub uid: String,
+    /// Give a username of the new sub user id
+    pub username: String,
+    /// 1: normal sub account, 6: custodial sub account
+    pub member_type: SubMemberType,
+    /// The status of the user account. 1: normal, 2: login banned, 4: frozen
+    pub status: SubMemberStatus,
+    /// The account mode of the user account. Not returned on creation
+    pub account_mode: Option<UnifiedMarginStatus>,
+    /// The remark
+    pub remark: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubMembers {
+    pub sub_members: Vec<SubMember>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct FreezeSubMemberParams {
+    /// Sub user Id
+    pub subuid: u64,
+    /// 0: unfreeze, 1: freeze
+    #[serde(serialize_with = "bool_to_int")]
+    pub frozen: bool,
+}
+
+impl FreezeSubMemberParams {
+    pub fn new(subuid: u64, frozen: bool) -> Self {
+        Self { subuid, frozen }
+    }
+}
+
+/// API key permissions. A missing group grants nothing.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ApiKeyPermissions {
+    /// Contract Trade. ["Order","Position"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract_trade: Option<Vec<String>>,
+    /// Spot Trade. ["SpotTrade"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spot: Option<Vec<String>>,
+    /// Wallet. ["AccountTransfer","SubMemberTransferList"]. Note: Withdraw is not supported for sub account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wallet: Option<Vec<String>>,
+    /// USDC Contract. ["OptionsTrade"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<String>>,
+    /// This param is deprecated because system will automatically add this permission according to your account is UTA or Classic
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub derivatives: Option<Vec<String>>,
+    /// Convert. ["ExchangeHistory"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exchange: Option<Vec<String>>,
+    /// Earn product. ["Earn"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub earn: Option<Vec<String>>,
+    /// Copy trading. ["CopyTrading"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copy_trading: Option<Vec<String>>,
+    /// Block trade. ["BlockTrade"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_trade: Option<Vec<String>>,
+    /// Affiliate. ["Affiliate"]. Only for master account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub affiliate: Option<Vec<String>>,
+    /// NFT. ["NFTQueryProductList"]
+    #[serde(rename = "NFT", default, skip_serializing_if = "Option::is_none")]
+    pub nft: Option<Vec<String>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CreateSubApiKeyParams {
+    /// Sub user Id
+    pub subuid: u64,
+    /// Set a remark
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// false: Read and Write. true: Read only
+    #[serde(serialize_with = "bool_to_int")]
+    pub read_only: bool,
+    /// Set the IP bind. example: "192.168.0.1,192.168.0.2". Note: Don't pass ips or pass with "*" means no bind. No ip bound api key will be invalid after 90 days
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ips: Option<String>,
+    /// Tick the types of permission
+    pub permissions: ApiKeyPermissions,
+}
+
+impl CreateSubApiKeyParams {
+    pub fn new(subuid: u64, read_only: bool, permissions: ApiKeyPermissions) -> Self {
+        Self {
+            subuid,
+            read_only,
+            permissions,
+            note: None,
+            ips: None,
+        }
+    }
+
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    pub fn ips(mut self, ips: impl Into<String>) -> Self {
+        self.ips = Some(ips.into());
+        self
+    }
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UpdateSubApiKeyParams {
+    /// Sub account api key. You must pass this param when you use master account manage sub account api key settings. If you use corresponding sub uid api key call this endpoint, apikey param cannot be passed, otherwise throwing an error
+    #[serde(rename = "apikey", skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// false (default): Read and Write. true: Read only
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_bool_to_int"
+    )]
+    pub read_only: Option<bool>,
+    /// Set the IP bind. example: "192.168.0.1,192.168.0.2". Note: Don't pass ips or pass with "*" means no bind
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ips: Option<String>,
+    /// Tick the types of permission. Don't send this param if you don't want to change the permission
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<ApiKeyPermissions>,
+}
+
+impl UpdateSubApiKeyParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = Some(read_only);
+        self
+    }
+
+    pub fn ips(mut self, ips: impl Into<String>) -> Self {
+        self.ips = Some(ips.into());
+        self
+    }
+
+    pub fn permissions(mut self, permissions: ApiKeyPermissions) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DeleteSubApiKeyParams {
+    /// Sub account api key. You must pass this param when you use master account manage sub account api key settings. If you use corresponding sub uid api key call this endpoint, apikey param cannot be passed, otherwise throwing an error
+    #[serde(rename = "apikey", skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+}
+
+impl DeleteSubApiKeyParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubApiKey {
+    /// Unique id. Internal used
+    pub id: String,
+    /// The remark
+    pub note: String,
+    /// Api key
+    pub api_key: String,
+    /// false: Read and Write. true: Read only
+    #[serde(deserialize_with = "int_to_bool")]
+    pub read_only: bool,
+    /// The secret paired with api key. The secret can't be queried by GET api. Please keep it properly. It is empty when the key is modified
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub secret: Option<SensitiveString>,
+    /// The types of permission
+    pub permissions: ApiKeyPermissions,
+    /// IP bound. Returned when the key is modified
+    pub ips: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetLeveragedTokenInfoParams {
+    /// Abbreviation of the LT, such as BTC3L
+    pub lt_coin: Option<String>,
+}
+
+impl GetLeveragedTokenInfoParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn lt_coin(mut self, lt_coin: impl Into<String>) -> Self {
+        self.lt_coin = Some(lt_coin.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenInfo {
+    /// Abbreviation of the LT, such as BTC3L
+    pub lt_coin: String,
+    /// Full name of leveraged token, such as BTC3L
+    pub lt_name: String,
+    /// Single maximum purchase amount
+    pub max_purchase: Decimal,
+    /// Single minimum purchase amount
+    pub min_purchase: Decimal,
+    /// Maximum purchase amount in a single day
+    pub max_purchase_daily: Decimal,
+    /// Single Maximum redemption quantity
+    pub max_redeem: Decimal,
+    /// Single Minimum redemption quantity
+    pub min_redeem: Decimal,
+    /// Maximum redemption quantity in a single day
+    pub max_redeem_daily: Decimal,
+    /// Purchase fee rate
+    pub purchase_fee_rate: Decimal,
+    /// Redeem fee rate
+    pub redeem_fee_rate: Decimal,
+    /// Whether the leverage token can be purchased or redeemed
+    pub lt_status: LtStatus,
+    /// Funding fee charged daily for users holding leveraged token
+    pub fund_fee: Decimal,
+    /// The time to charge funding fee (ms)
+    #[serde(deserialize_with = "number")]
+    pub fund_fee_time: Timestamp,
+    /// Management fee rate
+    pub manage_fee_rate: Decimal,
+    /// The time to charge management fee (ms)
+    #[serde(deserialize_with = "number")]
+    pub manage_fee_time: Timestamp,
+    /// Market value
+    pub value: Decimal,
+    /// Net value
+    pub net_value: Decimal,
+    /// Total purchase upper limit
+    pub total: Decimal,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetLeveragedTokenMarketParams {
+    /// Abbreviation of the LT, such as BTC3L
+    pub lt_coin: String,
+}
+
+impl GetLeveragedTokenMarketParams {
+    pub fn new(lt_coin: impl Into<String>) -> Self {
+        Self {
+            lt_coin: lt_coin.into(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenMarket {
+    /// Abbreviation of the LT, such as BTC3L
+    pub lt_coin: String,
+    /// Net value
+    pub nav: Decimal,
+    /// Update time for net asset value (ms)
+    #[serde(deserialize_with = "number")]
+    pub nav_time: Timestamp,
+    /// Circulating supply in the secondary market
+    pub circulation: Decimal,
+    /// Basket
+    pub basket: Decimal,
+    /// Real leverage calculated by last traded price
+    pub leverage: Decimal,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PurchaseLeveragedTokenParams {
+    /// Abbreviation of the LT, such as BTC3L
+    pub lt_coin: String,
+    /// Purchase amount
+    pub amount: Decimal,
+    /// Serial number
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial_no: Option<String>,
+}
+
+impl PurchaseLeveragedTokenParams {
+    pub fn new(lt_coin: impl Into<String>, amount: Decimal) -> Self {
+        Self {
+            lt_coin: lt_coin.into(),
+            amount,
+            serial_no: None,
+        }
+    }
+
+    pub fn serial_no(mut self, serial_no: impl Into<String>) -> Self {
+        self.serial_no = Some(serial_no.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenPurchase {
+    /// Abbreviation of the LT, such as BTC3L
+    pub lt_coin: String,
+    /// Order status
+    pub lt_order_status: LtOrderStatus,
+    /// Executed qty of LT
+    pub exec_qty: Decimal,
+    /// Executed amount of LT
+    pub exec_amt: Decimal,
+    /// Purchase amount
+    pub amount: Decimal,
+    /// Order ID
+    pub purchase_id: String,
+    /// Serial number
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub serial_no: Option<String>,
+    /// Quote coin
+    pub value_coin: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RedeemLeveragedTokenParams {
+    /// Abbreviation of the LT, such as BTC3L
+    pub lt_coin: String,
+    /// Redeem quantity of LT
+    pub quantity: Decimal,
+    /// Serial number
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial_no: Option<String>,
+}
+
+impl RedeemLeveragedTokenParams {
+    pub fn new(lt_coin: impl Into<String>, quantity: Decimal) -> Self {
+        Self {
+            lt_coin: lt_coin.into(),
+            quantity,
+            serial_no: None,
+        }
+    }
+
+    pub fn serial_no(mut self, serial_no: impl Into<String>) -> Self {
+        self.serial_no = Some(serial_no.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenRedemption {
+    /// Abbreviation of the LT, such as BTC3L
+    pub lt_coin: String,
+    /// Order status
+    pub lt_order_status: LtOrderStatus,
+    /// Quantity
+    pub quantity: Decimal,
+    /// Executed qty of LT
+    pub exec_qty: Decimal,
+    /// Executed amount of LT
+    pub exec_amt: Decimal,
+    /// Order ID
+    pub redeem_id: String,
+    /// Serial number
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub serial_no: Option<String>,
+    /// Quote coin
+    pub value_coin: String,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetLeveragedTokenOrdersParams {
+    /// Abbreviation of the LT, such as BTC3L
+    pub lt_coin: Option<String>,
+    /// Order ID
+    pub order_id: Option<String>,
+    /// Start time (ms)
+    pub start_time: Option<Timestamp>,
+    /// End time (ms)
+    pub end_time: Option<Timestamp>,
+    /// Limit for data size per page. [1, 500]. Default: 100
+    pub limit: Option<u64>,
+    /// LT order type. 1: purchase, 2: redemption
+    pub lt_order_type: Option<LtOrderType>,
+    /// Serial number
+    pub serial_no: Option<String>,
+}
+
+impl GetLeveragedTokenOrdersParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn lt_coin(mut self, lt_coin: impl Into<String>) -> Self {
+        self.lt_coin = Some(lt_coin.into());
+        self
+    }
+
+    pub fn order_id(mut self, order_id: impl Into<String>) -> Self {
+        self.order_id = Some(order_id.into());
+        self
+    }
+
+    pub fn start_time(mut self, start_time: Timestamp) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: Timestamp) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn lt_order_type(mut self, lt_order_type: LtOrderType) -> Self {
+        self.lt_order_type = Some(lt_order_type);
+        self
+    }
+
+    pub fn serial_no(mut self, serial_no: impl Into<String>) -> Self {
+        self.serial_no = Some(serial_no.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenOrder {
+    /// Abbreviation of the LT, such as BTC3L
+    pub lt_coin: String,
+    /// Order ID
+    pub order_id: String,
+    /// LT order type. 1: purchase, 2: redemption
+    pub lt_order_type: LtOrderType,
+    /// Order time (ms)
+    #[serde(deserialize_with = "number")]
+    pub order_time: Timestamp,
+    /// Last update time of the order status (ms)
+    #[serde(deserialize_with = "number")]
+    pub update_time: Timestamp,
+    /// Order status
+    pub lt_order_status: LtOrderStatus,
+    /// Trading fees
+    pub fee: Decimal,
+    /// Order quantity of the LT
+    pub amount: Decimal,
+    /// Filled value
+    pub value: Decimal,
+    /// Quote coin
+    pub value_coin: String,
+    /// Serial number
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub serial_no: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SwitchSpotMarginModeParams {
+    /// true: on, false: off
+    #[serde(serialize_with = "bool_to_string")]
+    pub spot_margin_mode: bool,
+}
+
+impl SwitchSpotMarginModeParams {
+    pub fn new(spot_margin_mode: bool) -> Self {
+        Self { spot_margin_mode }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotMarginMode {
+    /// Spot margin status. true: on, false: off
+    #[serde(deserialize_with = "string_to_bool")]
+    pub spot_margin_mode: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SetSpotMarginLeverageParams {
+    /// Leverage. [2, 10]
+    pub leverage: Decimal,
+}
+
+impl SetSpotMarginLeverageParams {
+    pub fn new(leverage: Decimal) -> Self {
+        Self { leverage }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SetPledgeTokenParams {
+    /// Coin name, uppercase only
+    pub coin: String,
+    /// Whether the coin is used as collateral for spot margin. ON, OFF
+    #[serde(rename = "collateralSwitch", serialize_with = "bool_to_on_off")]
+    pub pledge: bool,
+}
+
+impl SetPledgeTokenParams {
+    pub fn new(coin: impl Into<String>, pledge: bool) -> Self {
+        Self {
+            coin: coin.into(),
+            pledge,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotMarginState {
+    /// Spot margin leverage. Returns "" if the margin trade is turned off
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub spot_leverage: Option<Decimal>,
+    /// Spot margin status. true: on, false: off
+    #[serde(deserialize_with = "string_to_bool")]
+    pub spot_margin_mode: bool,
+    /// actual leverage ratio. Precision retains 2 decimal places, truncate downwards
+    pub effective_leverage: Decimal,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetVipMarginDataParams {
+    /// Vip level
+    pub vip_level: Option<VipLevel>,
+    /// Coin name, uppercase only
+    pub currency: Option<String>,
+}
+
+impl GetVipMarginDataParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn vip_level(mut self, vip_level: VipLevel) -> Self {
+        self.vip_level = Some(vip_level);
+        self
+    }
+
+    pub fn currency(mut self, currency: impl Into<String>) -> Self {
+        self.currency = Some(currency.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VipMarginData {
+    pub vip_coin_list: Vec<VipMarginCoins>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VipMarginCoins {
+    pub list: Vec<VipMarginCoin>,
+    /// Vip level
+    pub vip_level: VipLevel,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VipMarginCoin {
+    /// Whether it is allowed to be borrowed
+    pub borrowable: bool,
+    /// Due to the new Tiered Collateral value logic, this field will no longer be accurate starting on February 19, 2025
+    pub collateral_ratio: Decimal,
+    /// Coin name
+    pub currency: String,
+    /// Borrow interest rate per hour
+    pub hourly_borrow_rate: Decimal,
+    /// Liquidation order
+    pub liquidation_order: i64,
+    /// Whether it can be used as a margin collateral currency
+    pub margin_collateral: bool,
+    /// Max borrow amount
+    pub max_borrowing_amount: Decimal,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SpotBorrowCheckParams {
+    /// Product type. spot
+    pub category: Category,
+    /// Symbol name
+    pub symbol: String,
+    /// Transaction side. Buy,Sell
+    pub side: Side,
+}
+
+impl SpotBorrowCheckParams {
+    pub fn new(category: Category, symbol: impl Into<String>, side: Side) -> Self {
+        Self {
+            category,
+            symbol: symbol.into(),
+            side,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotBorrowCheck {
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: String,
+    /// Side
+    pub side: Side,
+    /// The maximum base coin qty can be traded. If spot margin trade on and symbol is margin trading pair, it returns available balance + max.borrowable quantity
+    pub max_trade_qty: Decimal,
+    /// The maximum quote coin amount can be traded. If spot margin trade on and symbol is margin trading pair, it returns available balance + max.borrowable amount
+    pub max_trade_amount: Decimal,
+    /// No matter your Spot margin switch on or not, it always returns actual qty of base coin you can trade or you have (borrowable qty is not included)
+    pub spot_max_trade_qty: Decimal,
+    /// No matter your Spot margin switch on or not, it always returns actual amount of quote coin you can trade or you have (borrowable amount is not included)
+    pub spot_max_trade_amount: Decimal,
+    /// Borrow coin
+    pub borrow_coin: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetConvertCoinsParams {
+    /// Wallet type
+    pub account_type: ConvertAccountType,
+    /// Coin, uppercase only
+    /// Convert from coin (coin to sell)
+    /// when side=0, coin field is ignored
+    pub coin: Option<String>,
+    /// 0: fromCoin list, the balance is given if you have it. 1: toCoin list (coin to buy)
+    /// when side=1 and coin field is filled, it returns toCoin list based on coin field
+    pub side: Option<ConvertSide>,
+}
+
+impl GetConvertCoinsParams {
+    pub fn new(account_type: ConvertAccountType) -> Self {
+        Self {
+            account_type,
+            coin: None,
+            side: None,
+        }
+    }
+
+    pub fn coin(mut self, coin: impl Into<String>) -> Self {
+        self.coin = Some(coin.into());
+        self
+    }
+
+    pub fn side(mut self, side: ConvertSide) -> Self {
+        self.side = Some(side);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertCoins {
+    pub coins: Vec<ConvertCoin>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertCoin {
+    /// Coin
+    pub coin: String,
+    /// Full coin name
+    pub full_name: String,
+    /// Coin precision
+    pub accuracy_length: u32,
+    /// crypto
+    pub coin_type: String,
+    /// Balance. When side=0, it gives available balance but cannot used to convert
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub balance: Option<Decimal>,
+    /// Coin balance in USDT worth value. When side=0, it gives available balance but cannot used to convert
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub u_balance: Option<Decimal>,
+    /// The minimum amount of fromCoin per transaction
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub single_from_min_limit: Option<Decimal>,
+    /// The maximum amount of fromCoin per transaction
+    #[serde(default, deserialize_with = "option_decimal")]
+    pub single_from_max_limit: Option<Decimal>,
+    /// true: the coin is disabled to be fromCoin
+    pub disable_from: bool,
+    /// true: the coin is disabled to be toCoin
+    pub disable_to: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RequestQuoteParams {
+    /// Convert from coin (coin to sell)
+    pub from_coin: String,
+    /// Convert to coin (coin to buy)
+    pub to_coin: String,
+    /// Request coin, same as fromCoin
+    pub request_coin: String,
+    /// request coin amount (the amount you want to sell)
+    pub request_amount: Decimal,
+    /// Wallet type
+    pub account_type: ConvertAccountType,
+    /// The custom ID for the quote
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+impl RequestQuoteParams {
+    /// Quote selling `request_amount` of `from_coin`.
+    pub fn new(
+        from_coin: impl Into<String>,
+        to_coin: impl Into<String>,
+        request_amount: Decimal,
+        account_type: ConvertAccountType,
+    ) -> Self {
+        let from_coin = from_coin.into();
+        Self {
+            request_coin: from_coin.clone(),
+            from_coin,
+            to_coin: to_coin.into(),
+            request_amount,
+            account_type,
+            request_id: None,
+        }
+    }
+
+    pub fn request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertQuote {
+    /// Quote transaction ID. It is system generated, and it is used to confirm quote and query the result of transaction
+    pub quote_tx_id: String,
+    /// Exchange rate
+    pub exchange_rate: Decimal,
+    /// From coin
+    pub from_coin: String,
+    /// From coin type. crypto
+    pub from_coin_type: String,
+    /// To coin
+    pub to_coin: String,
+    /// To coin type. crypto
+    pub to_coin_type: String,
+    /// From coin amount (amount to sell)
+    pub from_amount: Decimal,
+    /// To coin amount (amount to buy according to exchange rate)
+    pub to_amount: Decimal,
+    /// The expiry time for this quote (15 seconds)
+    #[serde(deserialize_with = "number")]
+    pub expired_time: Timestamp,
+    /// Customised request ID
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub request_id: Option<String>,
+}
+
+impl ConvertQuote {
+    /// Time (ms) left until the quote expires, zero if it has expired.
+    pub fn time_left(&self, now: Timestamp) -> Timestamp {
+        self.expired_time.saturating_sub(now)
+    }
+
+    /// Whether the quote can still be confirmed at `now` (ms).
+    pub fn is_valid(&self, now: Timestamp) -> bool {
+        now < self.expired_time
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ConfirmQuoteParams {
+    /// The quote tx ID from Request a Quote
+    pub quote_tx_id: String,
+}
+
+impl ConfirmQuoteParams {
+    pub fn new(quote_tx_id: impl Into<String>) -> Self {
+        Self {
+            quote_tx_id: quote_tx_id.into(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmQuoteResult {
+    /// Quote transaction ID
+    pub quote_tx_id: String,
+    /// Exchange status
+    pub exchange_status: ConvertStatus,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetConvertStatusParams {
+    /// Quote tx ID
+    pub quote_tx_id: String,
+    /// Wallet type
+    pub account_type: ConvertAccountType,
+}
+
+impl GetConvertStatusParams {
+    pub fn new(quote_tx_id: impl Into<String>, account_type: ConvertAccountType) -> Self {
+        Self {
+            quote_tx_id: quote_tx_id.into(),
+            account_type,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertStatusResult {
+    pub result: Convert,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Convert {
+    /// Wallet type
+    pub account_type: ConvertAccountType,
+    /// Quote transaction ID
+    pub exchange_tx_id: String,
+    /// User ID
+    pub user_id: String,
+    /// From coin
+    pub from_coin: String,
+    /// From coin type. crypto
+    pub from_coin_type: String,
+    /// To coin
+    pub to_coin: String,
+    /// To coin type. crypto
+    pub to_coin_type: String,
+    /// From coin amount (amount to sell)
+    pub from_amount: Decimal,
+    /// To coin amount (amount to buy according to exchange rate)
+    pub to_amount: Decimal,
+    /// Exchange status
+    pub exchange_status: ConvertStatus,
+    /// Exchange rate
+    pub convert_rate: Decimal,
+    /// Quote created time (ms)
+    #[serde(deserialize_with = "number")]
+    pub created_at: Timestamp,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetConvertHistoryParams {
+    /// Wallet type. Supports passing multiple types, separated by comma e.g., eb_convert_funding,eb_convert_uta
+    /// Return all wallet types data if not passed
+    pub account_type: Option<String>,
+    /// Page number. started from 1. 1st page by default
+    pub index: Option<u64>,
+    /// Page size. 20 records by default. up to 100 records, return 100 when exceeds 100
+    pub limit: Option<u64>,
+}
+
+impl GetConvertHistoryParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn account_type(mut self, account_type: impl Into<String>) -> Self {
+        self.account_type = Some(account_type.into());
+        self
+    }
+
+    pub fn index(mut self, index: u64) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetExchangeRecordsParams {
+    /// The currency to convert from, uppercase only. e.g,BTC
+    pub from_coin: Option<String>,
+    /// The currency to convert to, uppercase only. e.g,USDT
+    pub to_coin: Option<String>,
+    /// Limit for data size per page. [1, 50]. Default: 10
+    pub limit: Option<u64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+impl GetExchangeRecordsParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_coin(mut self, from_coin: impl Into<String>) -> Self {
+        self.from_coin = Some(from_coin.into());
+        self
+    }
+
+    pub fn to_coin(mut self, to_coin: impl Into<String>) -> Self {
+        self.to_coin = Some(to_coin.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeRecords {
+    /// Refer to the cursor request parameter
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub next_page_cursor: Option<String>,
+    pub order_body: Vec<ExchangeRecord>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeRecord {
+    /// The currency to convert from
+    pub from_coin: String,
+    /// The amount to convert from
+    pub from_amount: Decimal,
+    /// The currency to convert to
+    pub to_coin: String,
+    /// The amount to convert to
+    pub to_amount: Decimal,
+    /// Exchange rate
+    pub exchange_rate: Decimal,
+    /// Exchange created timestamp (sec)
+    #[serde(deserialize_with = "number")]
+    pub created_time: u64,
+    /// Exchange transaction ID
+    pub exchange_tx_id: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SetDcpParams {
+    /// OPTIONS(default), DERIVATIVES, SPOT
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<DcpProduct>,
+    /// Disconnection timing window time. [3, 300], unit: second
+    pub time_window: u64,
+}
+
+impl SetDcpParams {
+    pub fn new(time_window: u64) -> Self {
+        Self {
+            time_window,
+            product: None,
+        }
+    }
+
+    pub fn product(mut self, product: DcpProduct) -> Self {
+        self.product = Some(product);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use crate::v5::serde::deserialize_str;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_response_kline_inverse() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "symbol": "BTCUSD",
+                "category": "inverse",
+                "list": [
+                    [
+                        "1670608800000",
+                        "17071",
+                        "17073",
+                        "17027",
+                        "17055.5",
+                        "268611",
+                        "15.74462667"
+                    ],
+                    [
+                        "1670605200000",
+                        "17071.5",
+                        "17071.5",
+                        "17061",
+                        "17071",
+                        "4177",
+                        "0.24469757"
+                    ],
+                    [
+                        "1670601600000",
+                        "17086.5",
+                        "17088",
+                        "16978",
+                        "17071.5",
+                        "6356",
+                        "0.37288112"
+                    ]
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1672025956592
+        }"#;
+        let message: Resp<KLine> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: KLine::Inverse {
+                symbol: String::from("BTCUSD"),
+                list: vec![
+                    KLineRow {
+                        start_time: 1670608800000,
+                        open_price: dec!(17071),
+                        high_price: dec!(17073),
+                        low_price: dec!(17027),
+                        close_price: dec!(17055.5),
+                        volume: dec!(268611),
+                        turnover: dec!(15.74462667),
+                    },
+                    KLineRow {
+                        start_time: 1670605200000,
+                        open_price: dec!(17071.5),
+                        high_price: dec!(17071.5),
+                        low_price: dec!(17061),
+                        close_price: dec!(17071),
+                        volume: dec!(4177),
+                        turnover: dec!(0.24469757),
+                    },
+                    KLineRow {
+                        start_time: 1670601600000,
+                        open_price: dec!(17086.5),
+                        high_price: dec!(17088),
+                        low_price: dec!(16978),
+                        close_price: dec!(17071.5),
+                        volume: dec!(6356),
+                        turnover: dec!(0.37288112),
+                    },
+                ],
+            },
+            time: 1672025956592,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_ticker_inverse() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "category": "inverse",
+                "list": [
+                    {
+                        "symbol": "BTCUSD",
+                        "lastPrice": "16597.00",
+                        "indexPrice": "16598.54",
+                        "markPrice": "16596.00",
+                        "prevPrice24h": "16464.50",
+                        "price24hPcnt": "0.008047",
+                        "highPrice24h": "30912.50",
+                        "lowPrice24h": "15700.00",
+                        "prevPrice1h": "16595.50",
+                        "openInterest": "373504107",
+                        "openInterestValue": "22505.67",
+                        "turnover24h": "2352.94950046",
+                        "volume24h": "49337318",
+                        "fundingRate": "-0.001034",
+                        "nextFundingTime": "1672387200000",
+                        "predictedDeliveryPrice": "",
+                        "basisRate": "",
+                        "deliveryFeeRate": "",
+                        "deliveryTime": "0",
+                        "ask1Size": "1",
+                        "bid1Price": "16596.00",
+                        "ask1Price": "16597.50",
+                        "bid1Size": "1",
+                        "basis": ""
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1672376496682
+        }"#;
+        let message: Resp<Ticker> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: Ticker::Inverse {
+                list: vec![LinearInverseTicker {
+                    symbol: String::from("BTCUSD"),
+                    last_price: dec!(16597.00),
+                    mark_price: dec!(16596.00),
+                    index_price: dec!(16598.54),
+                    prev_price24h: dec!(16464.50),
+                    price24h_pcnt: dec!(0.008047),
+                    high_price24h: dec!(30912.50),
+                    low_price24h: dec!(15700.00),
+                    prev_price1h: dec!(16595.50),
+                    open_interest: dec!(373504107),
+                    open_interest_value: dec!(22505.67),
+                    turnover24h: dec!(2352.94950046),
+                    volume24h: dec!(49337318),
+                    funding_rate: Some(dec!(-0.001034)),
+                    next_funding_time: 1672387200000,
+                    predicted_delivery_price: None,
+                    basis_rate: None,
+                    basis: None,
+                    delivery_fee_rate: None,
+                    delivery_time: Some(0),
+                    bid1_price: dec!(16596.00),
+                    bid1_size: dec!(1),
+                    ask1_price: dec!(16597.50),
+                    ask1_size: dec!(1),
+                    pre_open_price: None,
+                    pre_qty: None,
+                    cur_pre_listing_phase: None,
+                }],
+            },
+            time: 1672376496682,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_trad_spot() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "category": "spot",
+                "list": [
+                    {
+                        "execId": "2100000000007764263",
+                        "symbol": "BTCUSDT",
+                        "price": "16618.49",
+                        "size": "0.00012",
+                        "side": "Buy",
+                        "time": "1672052955758",
+                        "isBlockTrade": false,
+                        "isRPITrade": true
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1672053054358
+        }"#;
+        let message: Resp<Trade> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: Trade::Spot {
+                list: vec![InverseLinearSpotTrade {
+                    exec_id: String::from("2100000000007764263"),
+                    symbol: String::from("BTCUSDT"),
+                    price: dec!(16618.49),
+                    size: dec!(0.00012),
+                    side: Side::Buy,
+                    time: 1672052955758,
+                    is_block_trade: false,
+                    is_rpi_trade: true,
+                }],
+            },
+            time: 1672053054358,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_get_open_closed_orders_linear() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "list": [
+                    {
+                        "orderId": "fd4300ae-7847-404e-b947-b46980a4d140",
+                        "orderLinkId": "test-000005",
+                        "blockTradeId": "",
+                        "symbol": "ETHUSDT",
+                        "price": "1600.00",
+                        "qty": "0.10",
+                        "side": "Buy",
+                        "isLeverage": "",
+                        "positionIdx": 1,
+                        "orderStatus": "New",
+                        "cancelType": "UNKNOWN",
+                        "rejectReason": "EC_NoError",
+                        "avgPrice": "0",
+                        "leavesQty": "0.10",
+                        "leavesValue": "160",
+                        "cumExecQty": "0.00",
+                        "cumExecValue": "0",
+                        "cumExecFee": "0",
+                        "timeInForce": "GTC",
+                        "orderType": "Limit",
+                        "stopOrderType": "UNKNOWN",
+                        "orderIv": "",
+                        "triggerPrice": "0.00",
+                        "takeProfit": "2500.00",
+                        "stopLoss": "1500.00",
+                        "tpTriggerBy": "LastPrice",
+                        "slTriggerBy": "LastPrice",
+                        "triggerDirection": 0,
+                        "triggerBy": "UNKNOWN",
+                        "lastPriceOnCreated": "",
+                        "reduceOnly": false,
+                        "closeOnTrigger": false,
+                        "smpType": "None",
+                        "smpGroup": 0,
+                        "smpOrderId": "",
+                        "tpslMode": "Full",
+                        "tpLimitPrice": "",
+                        "slLimitPrice": "",
+                        "placeType": "",
+                        "createdTime": "1684738540559",
+                        "updatedTime": "1684738540561"
+                    }
+                ],
+                "nextPageCursor": "page_args%3Dfd4300ae-7847-404e-b947-b46980a4d140%26symbol%3D6%26",
+                "category": "linear"
+            },
+            "retExtInfo": {},
+            "time": 1684765770483
+        }"#;
+        let message: Resp<CursorPagination<Order>> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: CursorPagination {
+                category: Category::Linear,
+                next_page_cursor: Some(String::from(
+                    "page_args%3Dfd4300ae-7847-404e-b947-b46980a4d140%26symbol%3D6%26",
+                )),
+                list: vec![Order {
+                    order_id: String::from("fd4300ae-7847-404e-b947-b46980a4d140"),
+                    order_link_id: Some(String::from("test-000005")),
+                    block_trade_id: None,
+                    symbol: String::from("ETHUSDT"),
+                    price: dec!(1600.00),
+                    qty: dec!(0.10),
+                    side: Side::Buy,
+                    is_leverage: None,
+                    position_idx: PositionIdx::Buy,
+                    order_status: OrderStatus::New,
+                    create_type: None,
+                    cancel_type: CancelType::UNKNOWN,
+                    reject_reason: RejectReason::EcNoError,
+                    avg_price: Some(dec!(0.0)),
+                    leaves_qty: dec!(0.10),
+                    leaves_value: dec!(160),
+                    cum_exec_qty: dec!(0.00),
+                    cum_exec_value: dec!(0),
+                    cum_exec_fee: dec!(0),
+                    time_in_force: TimeInForce::GTC,
+                    order_type: OrderType::Limit,
+                    stop_order_type: Some(StopOrderType::UNKNOWN),
+                    order_iv: None,
+                    market_unit: None,
+                    trigger_price: Some(dec!(0.00)),
+                    take_profit: Some(dec!(2500.00)),
+                    stop_loss: Some(dec!(1500.00)),
+                    tpsl_mode: Some(TpslMode::Full),
+                    oco_trigger_by: None,
+                    tp_limit_price: None,
+                    sl_limit_price: None,
+                    tp_trigger_by: Some(TriggerBy::LastPrice),
+                    sl_trigger_by: Some(TriggerBy::LastPrice),
+                    trigger_direction: TriggerDirection::UNKNOWN,
+                    trigger_by: TriggerBy::UNKNOWN,
+                    last_price_on_created: None,
+                    base_price: None,
+                    reduce_only: false,
+                    close_on_trigger: false,
+                    place_type: None,
+                    smp_type: SmpType::None,
+                    smp_group: 0,
+                    smp_order_id: None,
+                    created_time: 1684738540559,
+                    updated_time: 1684738540561,
+                }],
+            },
+            time: 1684765770483,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_get_position_info_inverse() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "list": [
+                    {
+                        "positionIdx": 0,
+                        "riskId": 1,
+                        "riskLimitValue": "150",
+                        "symbol": "BTCUSD",
+                        "side": "Sell",
+                        "size": "300",
+                        "avgPrice": "27464.50441675",
+                        "positionValue": "0.01092319",
+                        "tradeMode": 0,
+                        "positionStatus": "Normal",
+                        "autoAddMargin": 1,
+                        "adlRankIndicator": 2,
+                        "leverage": "10",
+                        "positionBalance": "0.00139186",
+                        "markPrice": "28224.50",
+                        "liqPrice": "",
+                        "bustPrice": "999999.00",
+                        "positionMM": "0.0000015",
+                        "positionIM": "0.00010923",
+                        "tpslMode": "Full",
+                        "takeProfit": "0.00",
+                        "stopLoss": "0.00",
+                        "trailingStop": "0.00",
+                        "unrealisedPnl": "-0.00029413",
+                        "curRealisedPnl": "0.00013123",
+                        "cumRealisedPnl": "-0.00096902",
+                        "seq": 5723621632,
+                        "isReduceOnly": false,
+                        "mmrSysUpdatedTime": "",
+                        "leverageSysUpdatedTime": "",
+                        "sessionAvgPrice": "",
+                        "createdTime": "1676538056258",
+                        "updatedTime": "1697673600012"
+                    }
+                ],
+                "nextPageCursor": "",
+                "category": "inverse"
+            },
+            "retExtInfo": {},
+            "time": 1697684980172
+        }"#;
+        let message: Resp<CursorPagination<Position>> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: CursorPagination {
+                category: Category::Inverse,
+                next_page_cursor: None,
+                list: vec![Position {
+                    position_idx: PositionIdx::OneWay,
+                    risk_id: 1,
+                    risk_limit_value: dec!(150),
+                    symbol: String::from("BTCUSD"),
+                    side: Some(Side::Sell),
+                    size: dec!(300),
+                    avg_price: dec!(27464.50441675),
+                    position_value: Some(dec!(0.01092319)),
+                    trade_mode: TradeMode::CrossMargin,
+                    auto_add_margin: true,
+                    position_status: PositionStatus::Normal,
+                    leverage: dec!(10),
+                    mark_price: dec!(28224.50),
+                    liq_price: None,
+                    bust_price: Some(dec!(999999.00)),
+                    position_im: dec!(0.00010923),
+                    position_mm: dec!(0.0000015),
+                    position_balance: dec!(0.00139186),
+                    take_profit: Some(dec!(0.00)),
+                    stop_loss: Some(dec!(0.00)),
+                    trailing_stop: dec!(0.00),
+                    session_avg_price: None,
+                    delta: None,
+                    gamma: None,
+                    vega: None,
+                    theta: None,
+                    unrealised_pnl: Some(dec!(-0.00029413)),
+                    cur_realised_pnl: dec!(0.00013123),
+                    cum_realised_pnl: dec!(-0.00096902),
+                    adl_rank_indicator: AdlRankIndicator::Two,
+                    created_time: 1676538056258,
+                    updated_time: 1697673600012,
+                    seq: 5723621632,
+                    is_reduce_only: false,
+                    mmr_sys_updated_time: None,
+                    leverage_sys_updated_time: None,
+                    tpsl_mode: TpslMode::Full,
+                }],
+            },
+            time: 1697684980172,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_funding_rate_history_linear() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "category": "linear",
+                "list": [
+                    {
+                        "symbol": "ETHPERP",
+                        "fundingRate": "0.0001",
+                        "fundingRateTimestamp": "1672041600000"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1672051897447
+        }"#;
+        let message: Resp<List<FundingRate>> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: List {
+                category: Some(Category::Linear),
+                next_page_cursor: None,
+                list: vec![FundingRate {
+                    symbol: String::from("ETHPERP"),
+                    funding_rate: dec!(0.0001),
+                    funding_rate_timestamp: 1672041600000,
+                }],
+            },
+            time: 1672051897447,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn next_funding_times() {
+        let json = r#"{
+            "symbol": "BTCUSDT",
+            "contractType": "LinearPerpetual",
+            "status": "Trading",
+            "baseCoin": "BTC",
+            "quoteCoin": "USDT",
+            "launchTime": "1585526400000",
+            "deliveryTime": "0",
+            "deliveryFeeRate": "",
+            "priceScale": "2",
+            "leverageFilter": {
+                "minLeverage": "1",
+                "maxLeverage": "100.00",
+                "leverageStep": "0.01"
+            },
+            "priceFilter": {
+                "minPrice": "0.10",
+                "maxPrice": "1999999.80",
+                "tickSize": "0.10"
+            },
+            "lotSizeFilter": {
+                "maxOrderQty": "1190.000",
+                "minOrderQty": "0.001",
+                "qtyStep": "0.001",
+                "postOnlyMaxOrderQty": "1190.000",
+                "maxMktOrderQty": "500.000",
+                "minNotionalValue": "5"
+            },
+            "unifiedMarginTrade": true,
+            "fundingInterval": 480,
+            "settleCoin": "USDT",
+            "copyTrading": "both",
+            "upperFundingRate": "0.00375",
+            "lowerFundingRate": "-0.00375",
+            "isPreListing": false,
+            "preListingInfo": null,
+            "riskParameters": {
+                "priceLimitRatioX": "0.01",
+                "priceLimitRatioY": "0.02"
+            }
+        }"#;
+        let info: InverseLinearInstrumentsInfo = deserialize_str(json).unwrap();
+        // 2022-12-26 10:51:37 UTC.
+        let now = 1672051897447;
+
+        let times = info.next_funding_times(now, 3);
+
+        // 2022-12-26 16:00, 2022-12-27 00:00, 2022-12-27 08:00 UTC.
+        assert_eq!(times, vec![1672070400000, 1672099200000, 1672128000000]);
+    }
+
+    #[test]
+    fn deserialize_response_open_interest_inverse() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "symbol": "BTCUSD",
+                "category": "inverse",
+                "list": [
+                    {
+                        "openInterest": "461134384.00000000",
+                        "timestamp": "1669571400000"
+                    },
+                    {
+                        "openInterest": "461134292.00000000",
+                        "timestamp": "1669571100000"
+                    }
+                ],
+                "nextPageCursor": ""
+            },
+            "retExtInfo": {},
+            "time": 1672053548579
+        }"#;
+        let message: Resp<CursorPagination<OpenInterest>> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: CursorPagination {
+                category: Category::Inverse,
+                next_page_cursor: None,
+                list: vec![
+                    OpenInterest {
+                        open_interest: dec!(461134384.00000000),
+                        timestamp: 1669571400000,
+                    },
+                    OpenInterest {
+                        open_interest: dec!(461134292.00000000),
+                        timestamp: 1669571100000,
+                    },
+                ],
+            },
+            time: 1672053548579,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_historical_volatility() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "SUCCESS",
+            "category": "option",
+            "result": [
+                {
+                    "period": 7,
+                    "value": "0.27545620",
+                    "time": "1672232400000"
+                }
+            ],
+            "retExtInfo": {},
+            "time": 1672232413574
+        }"#;
+        let message: Resp<Vec<HistoricalVolatility>> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("SUCCESS"),
+            result: vec![HistoricalVolatility {
+                period: 7,
+                value: dec!(0.27545620),
+                time: 1672232400000,
+            }],
+            time: 1672232413574,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_risk_limit_inverse() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "category": "inverse",
+                "list": [
+                    {
+                        "id": 1,
+                        "symbol": "BTCUSD",
+                        "riskLimitValue": "150",
+                        "maintenanceMargin": "0.5",
+                        "initialMargin": "1",
+                        "isLowestRisk": 1,
+                        "maxLeverage": "100.00",
+                        "mmDeduction": ""
+                    }
+                ],
+                "nextPageCursor": ""
+            },
+            "retExtInfo": {},
+            "time": 1672054488010
+        }"#;
+        let message: Resp<CursorPagination<RiskLimit>> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: CursorPagination {
+                category: Category::Inverse,
+                next_page_cursor: None,
+                list: vec![RiskLimit {
+                    id: 1,
+                    symbol: String::from("BTCUSD"),
+                    risk_limit_value: dec!(150),
+                    maintenance_margin: dec!(0.5),
+                    initial_margin: dec!(1),
+                    is_lowest_risk: true,
+                    max_leverage: dec!(100.00),
+                    mm_deduction: None,
+                }],
+            },
+            time: 1672054488010,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_delivery_price_option() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": {
+                "category": "option",
+                "nextPageCursor": "emptyCursor",
+                "list": [
+                    {
+                        "symbol": "ETH-26DEC22-1400-C",
+                        "deliveryPrice": "1220.728594450",
+                        "deliveryTime": "1672041600000"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1672055336993
+        }"#;
+        let message: Resp<CursorPagination<DeliveryPrice>> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("success"),
+            result: CursorPagination {
+                category: Category::Option,
+                next_page_cursor: Some(String::from("emptyCursor")),
+                list: vec![DeliveryPrice {
+                    symbol: String::from("ETH-26DEC22-1400-C"),
+                    delivery_price: dec!(1220.728594450),
+                    delivery_time: 1672041600000,
+                }],
+            },
+            time: 1672055336993,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_long_short_ratio() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "list": [
+                    {
+                        "symbol": "BTCUSDT",
+                        "buyRatio": "0.49",
+                        "sellRatio": "0.51",
+                        "timestamp": "1696320000000"
+                    }
+                ],
+                "nextPageCursor": "lastid%3D0%26lasttime%3D1696306800"
+            },
+            "retExtInfo": {},
+            "time": 1731567491688
+        }"#;
+        let message: Resp<List<LongShortRatio>> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: List {
+                category: None,
+                next_page_cursor: Some(String::from("lastid%3D0%26lasttime%3D1696306800")),
+                list: vec![LongShortRatio {
+                    symbol: String::from("BTCUSDT"),
+                    buy_ratio: dec!(0.49),
+                    sell_ratio: dec!(0.51),
+                    timestamp: 1696320000000,
+                }],
+            },
+            time: 1731567491688,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_system_status() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "list": [
+                    {
+                        "id": "4d95b2a0-587f-11f0-bcc9-56f28c94d6ea",
+                        "title": "t06",
+                        "state": "completed",
+                        "begin": "1751596902000",
+                        "end": "1751597011000",
+                        "href": "",
+                        "serviceTypes": [2, 3, 4, 5],
+                        "product": [1, 2],
+                        "uidSuffix": [],
+                        "maintainType": "1",
+                        "env": "1"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1751598345000
+        }"#;
+        let message: Resp<List<Maintenance>> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: List {
+                category: None,
+                next_page_cursor: None,
+                list: vec![Maintenance {
+                    id: String::from("4d95b2a0-587f-11f0-bcc9-56f28c94d6ea"),
+                    title: String::from("t06"),
+                    state: SystemState::Completed,
+                    begin: 1751596902000,
+                    end: 1751597011000,
+                    href: None,
+                    service_types: vec![2, 3, 4, 5],
+                    product: vec![1, 2],
+                    uid_suffix: vec![],
+                    maintain_type: String::from("1"),
+                    env: String::from("1"),
+                }],
+            },
+            time: 1751598345000,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_announcements() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "total": 735,
+                "list": [
+                    {
+                        "title": "New Listing: Arbitrum (ARB) — Deposit, Trade and StakeEarn to Share a 400,000 USDT Prize Pool!",
+                        "description": "Bybit is excited to announce the listing of Arbitrum (ARB) on our trading platform!",
+                        "type": {
+                            "title": "New Listings",
+                            "key": "new_crypto"
+                        },
+                        "tags": ["Spot", "Spot Listings"],
+                        "url": "https://announcements.bybit.com/en-US/article/new-listing-arbitrum-arb-deposit-trade-and-stakeearn-to-share-a-400-000-usdt-prize-pool--bltf662314c211a8616/",
+                        "dateTimestamp": 1679627520000,
+                        "startDateTimestamp": 1679627520000,
+                        "endDateTimestamp": 1679899200000
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1679415136117
+        }"#;
+        let message: Resp<Announcements> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: Announcements {
+                total: 735,
+                list: vec![Announcement {
+                    title: String::from(
+                        "New Listing: Arbitrum (ARB) — Deposit, Trade and StakeEarn to Share a 400,000 USDT Prize Pool!",
+                    ),
+                    description: String::from(
+                        "Bybit is excited to announce the listing of Arbitrum (ARB) on our trading platform!",
+                    ),
+                    announcement_type: AnnouncementTypeInfo {
+                        title: String::from("New Listings"),
+                        key: AnnouncementType::NewCrypto,
+                    },
+                    tags: vec![String::from("Spot"), String::from("Spot Listings")],
+                    url: String::from(
+                        "https://announcements.bybit.com/en-US/article/new-listing-arbitrum-arb-deposit-trade-and-stakeearn-to-share-a-400-000-usdt-prize-pool--bltf662314c211a8616/",
+                    ),
+                    date_timestamp: 1679627520000,
+                    start_date_timestamp: Some(1679627520000),
+                    end_date_timestamp: Some(1679899200000),
+                    publish_time: None,
+                }],
+            },
+            time: 1679415136117,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn serialize_set_trading_stop_params() {
+        let params = SetTradingStopParams {
+            category: Category::Linear,
+            symbol: String::from("XRPUSDT"),
+            tpsl_mode: TpslMode::Partial,
+            position_idx: PositionIdx::OneWay,
+            take_profit: Some(dec!(0.6)),
+            stop_loss: Some(dec!(0.2)),
+            trailing_stop: None,
+            tp_trigger_by: Some(TriggerBy::MarkPrice),
+            sl_trigger_by: Some(TriggerBy::IndexPrice),
+            active_price: None,
+            tp_size: Some(dec!(50)),
+            sl_size: Some(dec!(50)),
+            tp_limit_price: Some(dec!(0.57)),
+            sl_limit_price: Some(dec!(0.21)),
+            tp_order_type: Some(OrderType::Limit),
+            sl_order_type: Some(OrderType::Limit),
+        };
+        let expected = r#"{"category":"linear","symbol":"XRPUSDT","tpslMode":"Partial","positionIdx":0,"takeProfit":"0.6","stopLoss":"0.2","tpTriggerBy":"MarkPrice","slTriggerBy":"IndexPrice","tpSize":"50","slSize":"50","tpLimitPrice":"0.57","slLimitPrice":"0.21","tpOrderType":"Limit","slOrderType":"Limit"}"#;
+
+        let json = serde_json::to_string(&params).unwrap();
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn serialize_set_auto_add_margin_params() {
+        let params = SetAutoAddMarginParams {
+            category: Category::Linear,
+            symbol: String::from("BTCUSDT"),
+            auto_add_margin: true,
+            position_idx: None,
+        };
+        let expected = r#"{"category":"linear","symbol":"BTCUSDT","autoAddMargin":1}"#;
+
+        let json = serde_json::to_string(&params).unwrap();
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn deserialize_response_closed_pnl_linear() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "nextPageCursor": "5a373bfe-188d-4913-9c81-d57ab5be8068%3A1672214887231423699%2C5a373bfe-188d-4913-9c81-d57ab5be8068%3A1672214887231423699",
+                "category": "linear",
+                "list": [
+                    {
+                        "symbol": "ETHPERP",
+                        "orderType": "Market",
+                        "leverage": "3",
+                        "updatedTime": "1672214887236",
+                        "side": "Sell",
+                        "orderId": "5a373bfe-188d-4913-9c81-d57ab5be8068",
+                        "closedPnl": "-47.4065323",
+                        "avgEntryPrice": "1194.97516667",
+                        "qty": "3",
+                        "cumEntryValue": "3584.9255",
+                        "createdTime": "1672214887231",
+                        "orderPrice": "1122.95",
+                        "closedSize": "3",
+                        "avgExitPrice": "1180.59833333",
+                        "execType": "Trade",
+                        "fillCount": "4",
+                        "cumExitValue": "3541.795",
+                        "openFee": "0.0358",
+                        "closeFee": "0.0354"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1672284129153
+        }"#;
+        let message: Resp<CursorPagination<ClosedPnl>> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: CursorPagination {
+                category: Category::Linear,
+                next_page_cursor: Some(String::from(
+                    "5a373bfe-188d-4913-9c81-d57ab5be8068%3A1672214887231423699%2C5a373bfe-188d-4913-9c81-d57ab5be8068%3A1672214887231423699",
+                )),
+                list: vec![ClosedPnl {
+                    symbol: String::from("ETHPERP"),
+                    order_id: String::from("5a373bfe-188d-4913-9c81-d57ab5be8068"),
+                    side: Side::Sell,
+                    qty: dec!(3),
+                    order_price: dec!(1122.95),
+                    order_type: OrderType::Market,
+                    exec_type: ExecType::Trade,
+                    closed_size: dec!(3),
+                    cum_entry_value: dec!(3584.9255),
+                    avg_entry_price: dec!(1194.97516667),
+                    cum_exit_value: dec!(3541.795),
+                    avg_exit_price: dec!(1180.59833333),
+                    closed_pnl: dec!(-47.4065323),
+                    fill_count: 4,
+                    leverage: dec!(3),
+                    open_fee: Some(dec!(0.0358)),
+                    close_fee: Some(dec!(0.0354)),
+                    created_time: 1672214887231,
+                    updated_time: 1672214887236,
+                }],
+            },
+            time: 1672284129153,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_executions_linear() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "nextPageCursor": "132766%3A2%2C132766%3A2",
+                "category": "linear",
+                "list": [
+                    {
+                        "symbol": "ETHPERP",
+                        "orderType": "Market",
+                        "underlyingPrice": "",
+                        "orderLinkId": "",
+                        "side": "Buy",
+                        "indexPrice": "",
+                        "orderId": "8c065341-7b52-4ca9-ac2c-37e31ac55c94",
+                        "stopOrderType": "UNKNOWN",
+                        "leavesQty": "0",
+                        "execTime": "1672282722429",
+                        "feeCurrency": "",
+                        "isMaker": false,
+                        "execFee": "0.071409",
+                        "feeRate": "0.0006",
+                        "execId": "e0cbe81d-0f18-5866-9415-cf319b5dab3b",
+                        "tradeIv": "",
+                        "blockTradeId": "",
+                        "markPrice": "1183.54",
+                        "execPrice": "1190.15",
+                        "markIv": "",
+                        "orderQty": "0.1",
+                        "orderPrice": "1236.9",
+                        "execValue": "119.015",
+                        "execType": "Trade",
+                        "execQty": "0.1",
+                        "closedSize": "",
+                        "seq": 4688002127
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1672283754510
+        }"#;
+        let message: Resp<CursorPagination<Execution>> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: CursorPagination {
+                category: Category::Linear,
+                next_page_cursor: Some(String::from("132766%3A2%2C132766%3A2")),
+                list: vec![Execution {
+                    symbol: String::from("ETHPERP"),
+                    order_id: String::from("8c065341-7b52-4ca9-ac2c-37e31ac55c94"),
+                    order_link_id: None,
+                    side: Side::Buy,
+                    order_price: dec!(1236.9),
+                    order_qty: dec!(0.1),
+                    leaves_qty: dec!(0),
+                    create_type: None,
+                    order_type: OrderType::Market,
+                    stop_order_type: Some(StopOrderType::UNKNOWN),
+                    exec_fee: dec!(0.071409),
+                    exec_id: String::from("e0cbe81d-0f18-5866-9415-cf319b5dab3b"),
+                    exec_price: dec!(1190.15),
+                    exec_qty: dec!(0.1),
+                    exec_type: ExecType::Trade,
+                    exec_value: dec!(119.015),
+                    exec_time: 1672282722429,
+                    fee_currency: None,
+                    is_maker: false,
+                    fee_rate: Some(dec!(0.0006)),
+                    trade_iv: None,
+                    mark_iv: None,
+                    mark_price: Some(dec!(1183.54)),
+                    index_price: None,
+                    underlying_price: None,
+                    block_trade_id: None,
+                    closed_size: None,
+                    seq: 4688002127,
+                }],
+            },
+            time: 1672283754510,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_wallet_balance_unified() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "list": [
+                    {
+                        "totalEquity": "3.31216591",
+                        "accountIMRate": "0",
+                        "totalMarginBalance": "3.00326056",
+                        "totalInitialMargin": "0",
+                        "accountType": "UNIFIED",
+                        "totalAvailableBalance": "3.00326056",
+                        "accountMMRate": "0",
+                        "totalPerpUPL": "0",
+                        "totalWalletBalance": "3.00326056",
+                        "accountLTV": "0",
+                        "totalMaintenanceMargin": "0",
+                        "coin": [
+                            {
+                                "availableToBorrow": "3",
+                                "bonus": "0",
+                                "accruedInterest": "0",
+                                "availableToWithdraw": "0",
+                                "totalOrderIM": "0",
+                                "equity": "0",
+                                "totalPositionMM": "0",
+                                "usdValue": "0",
+                                "spotHedgingQty": "0.01592413",
+                                "unrealisedPnl": "0",
+                                "collateralSwitch": true,
+                                "borrowAmount": "0.0",
+                                "totalPositionIM": "0",
+                                "walletBalance": "0",
+                                "cumRealisedPnl": "0",
+                                "locked": "0",
+                                "marginCollateral": true,
+                                "coin": "BTC"
+                            }
+                        ]
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1690872862481
+        }"#;
+        let message: Resp<List<AccountWallet>> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: List {
+                category: None,
+                next_page_cursor: None,
+                list: vec![AccountWallet {
+                    account_type: AccountType::UNIFIED,
+                    account_ltv: Some(dec!(0)),
+                    account_im_rate: Some(dec!(0)),
+                    account_mm_rate: Some(dec!(0)),
+                    total_equity: Some(dec!(3.31216591)),
+                    total_wallet_balance: Some(dec!(3.00326056)),
+                    total_margin_balance: Some(dec!(3.00326056)),
+                    total_available_balance: Some(dec!(3.00326056)),
+                    total_perp_upl: Some(dec!(0)),
+                    total_initial_margin: Some(dec!(0)),
+                    total_maintenance_margin: Some(dec!(0)),
+                    coin: vec![CoinBalance {
+                        coin: String::from("BTC"),
+                        equity: dec!(0),
+                        usd_value: Some(dec!(0)),
+                        wallet_balance: dec!(0),
+                        free: None,
+                        locked: Some(dec!(0)),
+                        spot_hedging_qty: Some(dec!(0.01592413)),
+                        borrow_amount: Some(dec!(0.0)),
+                        accrued_interest: Some(dec!(0)),
+                        total_order_im: Some(dec!(0)),
+                        total_position_im: Some(dec!(0)),
+                        total_position_mm: Some(dec!(0)),
+                        unrealised_pnl: Some(dec!(0)),
+                        cum_realised_pnl: Some(dec!(0)),
+                        bonus: Some(dec!(0)),
+                        margin_collateral: Some(true),
+                        collateral_switch: Some(true),
+                    }],
+                }],
+            },
+            time: 1690872862481,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_account_info() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "marginMode": "REGULAR_MARGIN",
+                "updatedTime": "1697078946000",
+                "unifiedMarginStatus": 4,
+                "dcpStatus": "OFF",
+                "timeWindow": 10,
+                "smpGroup": 0,
+                "isMasterTrader": false,
+                "spotHedgingStatus": "OFF"
+            },
+            "retExtInfo": {},
+            "time": 1697078946000
+        }"#;
+        let message: Resp<AccountInfo> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: AccountInfo {
+                unified_margin_status: UnifiedMarginStatus::UnifiedTradingAccount1Pro,
+                margin_mode: MarginMode::RegularMargin,
+                is_master_trader: false,
+                spot_hedging_status: false,
+                updated_time: 1697078946000,
+                dcp_status: Some(false),
+                time_window: Some(10),
+                smp_group: Some(0),
+            },
+            time: 1697078946000,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_transaction_log() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "nextPageCursor": "21963%3A1%2C14954%3A1",
+                "list": [
+                    {
+                        "id": "592324_XRPUSDT_161440249321",
+                        "symbol": "XRPUSDT",
+                        "category": "linear",
+                        "side": "Buy",
+                        "transactionTime": "1682580468000",
+                        "type": "TRADE",
+                        "qty": "50",
+                        "size": "100",
+                        "currency": "USDT",
+                        "tradePrice": "0.4498",
+                        "funding": "",
+                        "fee": "0.01124",
+                        "cashFlow": "0",
+                        "change": "-0.01124",
+                        "cashBalance": "2.99744",
+                        "feeRate": "0.0005",
+                        "bonusChange": "",
+                        "tradeId": "b6938d20-f4c4-5e8c-bb4e-0f4b0d8b6b82",
+                        "orderId": "1d8ba1c8-60ec-4a27-b9a1-2f1e4e3d1a07",
+                        "orderLinkId": ""
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1682580468000
+        }"#;
+        let message: Resp<List<TransactionLog>> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: List {
+                category: None,
+                next_page_cursor: Some(String::from("21963%3A1%2C14954%3A1")),
+                list: vec![TransactionLog {
+                    id: String::from("592324_XRPUSDT_161440249321"),
+                    symbol: Some(String::from("XRPUSDT")),
+                    category: Some(Category::Linear),
+                    side: Some(Side::Buy),
+                    transaction_time: 1682580468000,
+                    transaction_type: Type::Trade,
+                    qty: Some(dec!(50)),
+                    size: Some(dec!(100)),
+                    currency: String::from("USDT"),
+                    trade_price: Some(dec!(0.4498)),
+                    funding: None,
+                    fee: Some(dec!(0.01124)),
+                    cash_flow: Some(dec!(0)),
+                    change: Some(dec!(-0.01124)),
+                    cash_balance: Some(dec!(2.99744)),
+                    fee_rate: Some(dec!(0.0005)),
+                    bonus_change: None,
+                    trade_id: Some(String::from("b6938d20-f4c4-5e8c-bb4e-0f4b0d8b6b82")),
+                    order_id: Some(String::from("1d8ba1c8-60ec-4a27-b9a1-2f1e4e3d1a07")),
+                    order_link_id: None,
+                }],
+            },
+            time: 1682580468000,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_collateral_info() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "list": [
+                    {
+                        "availableToBorrow": "3",
+                        "freeBorrowingAmount": "",
+                        "freeBorrowAmount": "0",
+                        "maxBorrowingAmount": "3",
+                        "hourlyBorrowRate": "0.00000147",
+                        "borrowUsageRate": "0",
+                        "collateralSwitch": true,
+                        "borrowAmount": "0",
+                        "borrowable": true,
+                        "currency": "BTC",
+                        "otherBorrowAmount": "0",
+                        "marginCollateral": true,
+                        "freeBorrowingLimit": "0",
+                        "collateralRatio": "0.95"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1691565901952
+        }"#;
+        let message: Resp<List<CollateralCoin>> = deserialize_str(json).unwrap();
+        let expected = Resp {
+            ret_code: 0,
+            ret_msg: String::from("OK"),
+            result: List {
+                category: None,
+                next_page_cursor: None,
+                list: vec![CollateralCoin {
+                    currency: String::from("BTC"),
+                    hourly_borrow_rate: Some(dec!(0.00000147)),
+                    max_borrowing_amount: Some(dec!(3)),
+                    free_borrowing_limit: Some(dec!(0)),
+                    free_borrow_amount: Some(dec!(0)),
+                    borrow_amount: Some(dec!(0)),
+                    other_borrow_amount: Some(dec!(0)),
+                    available_to_borrow: Some(dec!(3)),
+                    borrowable: true,
+                    borrow_usage_rate: Some(dec!(0)),
+                    margin_collateral: true,
+                    collateral_switch: true,
+                    collateral_ratio: Some(dec!(0.95)),
+                }],
+            },
+            time: 1691565901952,
+            ret_ext_info: RetExtInfo {},
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_set_margin_mode() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "Request accepted",
+            "result": {
+                "reasons": [
+                    {
+                        "reasonCode": "3400000",
+                        "reasonMsg": "Your total available balance is insufficient"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1697078946000
+        }"#;
+        let message: Resp<SetMarginModeResult> = deserialize_str(json).unwrap();
+        let expected = SetMarginModeResult {
+            reasons: vec![MarginModeReason {
+                reason_code: String::from("3400000"),
+                reason_msg: String::from("Your total available balance is insufficient"),
+            }],
+        };
+        assert_eq!(message.result, expected);
+    }
+
+    #[test]
+    fn deserialize_response_upgrade_to_uta() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "",
+            "result": {
+                "unifiedUpdateStatus": "FAIL",
+                "unifiedUpdateMsg": {
+                    "msg": [
+                        "Please close all open orders and positions before upgrading"
+                    ]
+                }
+            },
+            "retExtInfo": {},
+            "time": 1676538056258
+        }"#;
+        let message: Resp<UpgradeToUtaResult> = deserialize_str(json).unwrap();
+        let expected = UpgradeToUtaResult {
+            unified_update_status: UnifiedUpdateStatus::FAIL,
+            unified_update_msg: Some(UnifiedUpdateMsg {
+                msg: vec![String::from(
+                    "Please close all open orders and positions before upgrading",
+                )],
+            }),
+        };
+        assert_eq!(message.result, expected);
+    }
+
+    #[test]
+    fn serialize_request_demo_funds_params() {
+        let params = RequestDemoFundsParams {
+            adjust_type: Some(DemoAdjustType::Add),
+            uta_demo_apply_money: vec![
+                DemoApplyMoney {
+                    coin: String::from("USDT"),
+                    amount_str: dec!(100000),
+                },
+                DemoApplyMoney {
+                    coin: String::from("BTC"),
+                    amount_str: dec!(1.5),
+                },
+            ],
+        };
+        let expected = r#"{"adjustType":0,"utaDemoApplyMoney":[{"coin":"USDT","amountStr":"100000"},{"coin":"BTC","amountStr":"1.5"}]}"#;
+
+        let json = serde_json::to_string(&params).unwrap();
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn deserialize_response_fee_rates_spot() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "list": [
+                    {
+                        "symbol": "ETHUSDT",
+                        "takerFeeRate": "0.0006",
+                        "makerFeeRate": "0.0001"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1676360412576
+        }"#;
+        let message: Resp<List<FeeRate>> = deserialize_str(json).unwrap();
+        let expected = List {
+            category: None,
+            next_page_cursor: None,
+            list: vec![FeeRate {
+                symbol: String::from("ETHUSDT"),
+                base_coin: None,
+                taker_fee_rate: dec!(0.0006),
+                maker_fee_rate: dec!(0.0001),
+            }],
+        };
+        assert_eq!(message.result, expected);
+    }
+
+    #[test]
+    fn serialize_inter_transfer_params() {
+        let params =
+            InterTransferParams::new("USDT", dec!(100.5), AccountType::UNIFIED, AccountType::FUND);
+        let expected = format!(
+            r#"{{"transferId":"{}","coin":"USDT","amount":"100.5","fromAccountType":"UNIFIED","toAccountType":"FUND"}}"#,
+            params.transfer_id
+        );
 
-    use crate::v5::serde::deserialize_str;
+        let json = serde_json::to_string(&params).unwrap();
 
-    use super::*;
+        assert_eq!(json, expected);
+        assert!(Uuid::parse_str(&params.transfer_id).is_ok());
+    }
 
     #[test]
-    fn deserialize_response_kline_inverse() {
+    fn deserialize_response_universal_transfer_records() {
         let json = r#"{
             "retCode": 0,
-            "retMsg": "OK",
+            "retMsg": "success",
             "result": {
-                "symbol": "BTCUSD",
-                "category": "inverse",
                 "list": [
-                    [
-                        "1670608800000",
-                        "17071",
-                        "17073",
-                        "17027",
-                        "17055.5",
-                        "268611",
-                        "15.74462667"
-                    ],
-                    [
-                        "1670605200000",
-                        "17071.5",
-                        "17071.5",
-                        "17061",
-                        "17071",
-                        "4177",
-                        "0.24469757"
-                    ],
-                    [
-                        "1670601600000",
-                        "17086.5",
-                        "17088",
-                        "16978",
-                        "17071.5",
-                        "6356",
-                        "0.37288112"
-                    ]
+                    {
+                        "transferId": "universalTransfer_4c3cfe2f-85cb-11ed-ac09-9e37823c81cd_533285",
+                        "coin": "USDC",
+                        "amount": "1000.00000000",
+                        "timestamp": "1672134373000",
+                        "status": "SUCCESS",
+                        "fromAccountType": "UNIFIED",
+                        "toAccountType": "UNIFIED",
+                        "fromMemberId": "533285",
+                        "toMemberId": "592324"
+                    }
+                ],
+                "nextPageCursor": "eyJtaW5JRCI6MTc5NjU3OCwibWF4SUQiOjE3OTY1Nzh9"
+            },
+            "retExtInfo": {},
+            "time": 1672190762800
+        }"#;
+        let message: Resp<List<UniversalTransferRecord>> = deserialize_str(json).unwrap();
+        let expected = List {
+            category: None,
+            next_page_cursor: Some(String::from("eyJtaW5JRCI6MTc5NjU3OCwibWF4SUQiOjE3OTY1Nzh9")),
+            list: vec![UniversalTransferRecord {
+                transfer_id: String::from(
+                    "universalTransfer_4c3cfe2f-85cb-11ed-ac09-9e37823c81cd_533285",
+                ),
+                coin: String::from("USDC"),
+                amount: dec!(1000.00000000),
+                from_member_id: 533285,
+                to_member_id: 592324,
+                from_account_type: AccountType::UNIFIED,
+                to_account_type: AccountType::UNIFIED,
+                timestamp: 1672134373000,
+                status: TransferStatus::SUCCESS,
+            }],
+        };
+        assert_eq!(message.result, expected);
+    }
+
+    #[test]
+    fn serialize_transfer_params_retry() {
+        let params =
+            InterTransferParams::new("USDT", dec!(10), AccountType::UNIFIED, AccountType::FUND);
+        let body = serde_json::to_string(&params).unwrap();
+
+        let retry = params.clone();
+        assert_eq!(serde_json::to_string(&retry).unwrap(), body);
+
+        let retry =
+            InterTransferParams::new("USDT", dec!(10), AccountType::UNIFIED, AccountType::FUND)
+                .transfer_id(params.transfer_id.clone());
+        assert_eq!(serde_json::to_string(&retry).unwrap(), body);
+    }
+
+    #[test]
+    fn serialize_get_fee_rates_params() {
+        let query = serde_urlencoded::to_string(GetFeeRatesParams::option("ETH")).unwrap();
+
+        assert_eq!(query, "category=option&baseCoin=ETH");
+    }
+
+    #[test]
+    fn serialize_get_tickers_params() {
+        let params = GetTickersParams::new(Category::Option)
+            .base_coin("BTC")
+            .exp_date("25DEC22");
+
+        let query = serde_urlencoded::to_string(&params).unwrap();
+
+        assert_eq!(query, "category=option&baseCoin=BTC&expDate=25DEC22");
+    }
+
+    #[test]
+    fn serialize_get_instruments_info_params() {
+        let params = GetInstrumentsInfoParams::new(Category::Option)
+            .base_coin("ETH")
+            .limit(1000)
+            .cursor("next");
+
+        let query = serde_urlencoded::to_string(&params).unwrap();
+
+        assert_eq!(query, "category=option&baseCoin=ETH&limit=1000&cursor=next");
+    }
+
+    #[test]
+    fn deserialize_response_instruments_info_option() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": {
+                "category": "option",
+                "nextPageCursor": "",
+                "list": [
+                    {
+                        "symbol": "ETH-3JAN23-1250-P",
+                        "status": "Trading",
+                        "baseCoin": "ETH",
+                        "quoteCoin": "USD",
+                        "settleCoin": "USDC",
+                        "optionsType": "Put",
+                        "launchTime": "1672560000000",
+                        "deliveryTime": "1672732800000",
+                        "deliveryFeeRate": "0.00015",
+                        "priceFilter": {
+                            "minPrice": "0.1",
+                            "maxPrice": "10000000",
+                            "tickSize": "0.1"
+                        },
+                        "lotSizeFilter": {
+                            "maxOrderQty": "1500",
+                            "minOrderQty": "0.1",
+                            "qtyStep": "0.1"
+                        },
+                        "displayName": ""
+                    }
                 ]
             },
             "retExtInfo": {},
-            "time": 1672025956592
+            "time": 1672712537130
         }"#;
-        let message: Resp<KLine> = deserialize_str(json).unwrap();
+        let message: Resp<InstrumentsInfo> = deserialize_str(json).unwrap();
         let expected = Resp {
             ret_code: 0,
-            ret_msg: String::from("OK"),
-            result: KLine::Inverse {
-                symbol: String::from("BTCUSD"),
-                list: vec![
-                    KLineRow {
-                        start_time: 1670608800000,
-                        open_price: dec!(17071),
-                        high_price: dec!(17073),
-                        low_price: dec!(17027),
-                        close_price: dec!(17055.5),
-                        volume: dec!(268611),
-                        turnover: dec!(15.74462667),
-                    },
-                    KLineRow {
-                        start_time: 1670605200000,
-                        open_price: dec!(17071.5),
-                        high_price: dec!(17071.5),
-                        low_price: dec!(17061),
-                        close_price: dec!(17071),
-                        volume: dec!(4177),
-                        turnover: dec!(0.24469757),
+            ret_msg: String::from("success"),
+            result: InstrumentsInfo::Option {
+                next_page_cursor: String::new(),
+                list: vec![OptionInstrumentsInfo {
+                    symbol: String::from("ETH-3JAN23-1250-P"),
+                    status: Status::Trading,
+                    base_coin: String::from("ETH"),
+                    quote_coin: String::from("USD"),
+                    settle_coin: String::from("USDC"),
+                    options_type: String::from("Put"),
+                    launch_time: 1672560000000,
+                    delivery_time: 1672732800000,
+                    delivery_fee_rate: Some(0.00015),
+                    price_filter: PriceFilter {
+                        min_price: dec!(0.1),
+                        max_price: dec!(10000000),
+                        tick_size: dec!(0.1),
                     },
-                    KLineRow {
-                        start_time: 1670601600000,
-                        open_price: dec!(17086.5),
-                        high_price: dec!(17088),
-                        low_price: dec!(16978),
-                        close_price: dec!(17071.5),
-                        volume: dec!(6356),
-                        turnover: dec!(0.37288112),
+                    lot_size_filter: OptionLotSizeFilter {
+                        max_order_qty: dec!(1500),
+                        min_order_qty: dec!(0.1),
+                        qty_step: dec!(0.1),
                     },
-                ],
+                    display_name: String::new(),
+                }],
             },
-            time: 1672025956592,
+            time: 1672712537130,
             ret_ext_info: RetExtInfo {},
         };
         assert_eq!(message, expected);
     }
 
     #[test]
-    fn deserialize_response_ticker_inverse() {
+    fn serialize_get_coin_balance_params() {
+        let params = GetCoinBalanceParams {
+            member_id: None,
+            to_member_id: None,
+            account_type: AccountType::UNIFIED,
+            to_account_type: Some(AccountType::FUND),
+            coin: String::from("USDT"),
+            with_bonus: Some(false),
+            with_transfer_safe_amount: None,
+            with_ltv_transfer_safe_amount: None,
+        };
+
+        let query = serde_urlencoded::to_string(&params).unwrap();
+
+        assert_eq!(
+            query,
+            "accountType=UNIFIED&toAccountType=FUND&coin=USDT&withBonus=0"
+        );
+    }
+
+    #[test]
+    fn deserialize_response_account_coin_balance() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": {
+                "accountType": "UNIFIED",
+                "bizType": 1,
+                "accountId": "1631385",
+                "memberId": "1631373",
+                "balance": {
+                    "coin": "USDT",
+                    "walletBalance": "11999",
+                    "transferBalance": "11999",
+                    "bonus": "0",
+                    "transferSafeAmount": "",
+                    "ltvTransferSafeAmount": ""
+                }
+            },
+            "retExtInfo": {},
+            "time": 1702287335860
+        }"#;
+        let message: Resp<AccountCoinBalance> = deserialize_str(json).unwrap();
+        let expected = AccountCoinBalance {
+            account_type: AccountType::UNIFIED,
+            biz_type: 1,
+            account_id: String::from("1631385"),
+            member_id: String::from("1631373"),
+            balance: TransferCoinBalance {
+                coin: String::from("USDT"),
+                wallet_balance: dec!(11999),
+                transfer_balance: dec!(11999),
+                bonus: Some(dec!(0)),
+                transfer_safe_amount: None,
+                ltv_transfer_safe_amount: None,
+            },
+        };
+        assert_eq!(message.result, expected);
+    }
+
+    #[test]
+    fn deserialize_response_deposit_records() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": {
+                "rows": [
+                    {
+                        "id": "160237231",
+                        "coin": "USDT",
+                        "chain": "ETH",
+                        "amount": "10000",
+                        "txID": "skip-notification-scene-test-amount-202212270944-533285-USDT",
+                        "status": 3,
+                        "toAddress": "test-amount-address",
+                        "tag": "",
+                        "depositFee": "",
+                        "successAt": "1672134274000",
+                        "confirmations": "10000",
+                        "txIndex": "",
+                        "blockHash": "",
+                        "batchReleaseLimit": "-1",
+                        "depositType": "0"
+                    }
+                ],
+                "nextPageCursor": "eyJtaW5JRCI6MTA0NjA0MywibWF4SUQiOjEwNDYwNDN9"
+            },
+            "retExtInfo": {},
+            "time": 1672191992512
+        }"#;
+        let message: Resp<DepositRecords> = deserialize_str(json).unwrap();
+        let expected = DepositRecords {
+            next_page_cursor: Some(String::from("eyJtaW5JRCI6MTA0NjA0MywibWF4SUQiOjEwNDYwNDN9")),
+            rows: vec![DepositRecord {
+                id: Some(String::from("160237231")),
+                coin: String::from("USDT"),
+                chain: String::from("ETH"),
+                amount: dec!(10000),
+                tx_id: String::from("skip-notification-scene-test-amount-202212270944-533285-USDT"),
+                status: DepositStatus::Success,
+                to_address: String::from("test-amount-address"),
+                tag: None,
+                deposit_fee: None,
+                success_at: Some(1672134274000),
+                confirmations: 10000,
+                tx_index: None,
+                block_hash: None,
+                batch_release_limit: dec!(-1),
+                deposit_type: 0,
+            }],
+        };
+        assert_eq!(message.result, expected);
+        assert!(message.result.rows[0].status.is_final());
+    }
+
+    #[test]
+    fn deserialize_response_withdrawal_records() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": {
+                "rows": [
+                    {
+                        "coin": "USDT",
+                        "chain": "ETH",
+                        "amount": "77",
+                        "txID": "",
+                        "status": "SecurityCheck",
+                        "toAddress": "0x99ced129603abc771c0dabe935c326ff6c86645d",
+                        "tag": "",
+                        "withdrawFee": "10",
+                        "createTime": "1670922217000",
+                        "updateTime": "1670922217000",
+                        "withdrawId": "9976",
+                        "withdrawType": 0
+                    }
+                ],
+                "nextPageCursor": "eyJtaW5JRCI6OTk3NiwibWF4SUQiOjk5NzZ9"
+            },
+            "retExtInfo": {},
+            "time": 1670988051992
+        }"#;
+        let message: Resp<WithdrawalRecords> = deserialize_str(json).unwrap();
+        let expected = WithdrawalRecords {
+            next_page_cursor: Some(String::from("eyJtaW5JRCI6OTk3NiwibWF4SUQiOjk5NzZ9")),
+            rows: vec![WithdrawalRecord {
+                withdraw_id: String::from("9976"),
+                tx_id: None,
+                withdraw_type: WithdrawType::OnChain,
+                coin: String::from("USDT"),
+                chain: String::from("ETH"),
+                amount: dec!(77),
+                withdraw_fee: Some(dec!(10)),
+                status: WithdrawStatus::SecurityCheck,
+                to_address: String::from("0x99ced129603abc771c0dabe935c326ff6c86645d"),
+                tag: None,
+                create_time: 1670922217000,
+                update_time: 1670922217000,
+            }],
+        };
+        assert_eq!(message.result, expected);
+    }
+
+    #[test]
+    fn deserialize_response_coin_info() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "",
+            "result": {
+                "rows": [
+                    {
+                        "name": "MNT",
+                        "coin": "MNT",
+                        "remainAmount": "10000000",
+                        "chains": [
+                            {
+                                "chainType": "Ethereum",
+                                "confirmation": "6",
+                                "withdrawFee": "3",
+                                "depositMin": "0",
+                                "withdrawMin": "3",
+                                "chain": "ETH",
+                                "chainDeposit": "1",
+                                "chainWithdraw": "1",
+                                "minAccuracy": "8",
+                                "withdrawPercentageFee": "0",
+                                "contractAddress": "0x3c3a81e81dc49a522a592e7622a7e711c06bf354"
+                            }
+                        ]
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1736395486989
+        }"#;
+        let message: Resp<CoinsInfo> = deserialize_str(json).unwrap();
+        let expected = CoinsInfo {
+            rows: vec![CoinInfo {
+                name: String::from("MNT"),
+                coin: String::from("MNT"),
+                remain_amount: dec!(10000000),
+                chains: vec![ChainInfo {
+                    chain: String::from("ETH"),
+                    chain_type: String::from("Ethereum"),
+                    confirmation: 6,
+                    withdraw_fee: Some(dec!(3)),
+                    deposit_min: dec!(0),
+                    withdraw_min: dec!(3),
+                    min_accuracy: 8,
+                    chain_deposit: true,
+                    chain_withdraw: true,
+                    withdraw_percentage_fee: Some(dec!(0)),
+                    contract_address: Some(String::from(
+                        "0x3c3a81e81dc49a522a592e7622a7e711c06bf354",
+                    )),
+                }],
+            }],
+        };
+        assert_eq!(message.result, expected);
+    }
+
+    #[test]
+    fn deserialize_response_delivery_records() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "success",
+            "result": {
+                "nextPageCursor": "132791%3A0%2C132791%3A0",
+                "category": "option",
+                "list": [
+                    {
+                        "symbol": "BTC-14JAN23-17500-C",
+                        "side": "Buy",
+                        "deliveryTime": 1673683200000,
+                        "strike": "17500",
+                        "fee": "0.00000000",
+                        "position": "0.01",
+                        "deliveryPrice": "20881.80000000",
+                        "deliveryRpl": "3.38180000",
+                        "entryPrice": "18000"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1673689238406
+        }"#;
+        let message: Resp<CursorPagination<DeliveryRecord>> = deserialize_str(json).unwrap();
+        let expected = CursorPagination {
+            category: Category::Option,
+            next_page_cursor: Some(String::from("132791%3A0%2C132791%3A0")),
+            list: vec![DeliveryRecord {
+                delivery_time: 1673683200000,
+                symbol: String::from("BTC-14JAN23-17500-C"),
+                side: Side::Buy,
+                position: dec!(0.01),
+                entry_price: Some(dec!(18000)),
+                delivery_price: dec!(20881.80000000),
+                strike: Some(dec!(17500)),
+                fee: dec!(0.00000000),
+                delivery_rpl: dec!(3.38180000),
+            }],
+        };
+        assert_eq!(message.result, expected);
+    }
+
+    #[test]
+    fn deserialize_response_settlement_records() {
         let json = r#"{
             "retCode": 0,
             "retMsg": "OK",
             "result": {
-                "category": "inverse",
+                "nextPageCursor": "116952%3A1%2C116952%3A1",
+                "category": "linear",
                 "list": [
                     {
-                        "symbol": "BTCUSD",
-                        "lastPrice": "16597.00",
-                        "indexPrice": "16598.54",
-                        "markPrice": "16596.00",
-                        "prevPrice24h": "16464.50",
-                        "price24hPcnt": "0.008047",
-                        "highPrice24h": "30912.50",
-                        "lowPrice24h": "15700.00",
-                        "prevPrice1h": "16595.50",
-                        "openInterest": "373504107",
-                        "openInterestValue": "22505.67",
-                        "turnover24h": "2352.94950046",
-                        "volume24h": "49337318",
-                        "fundingRate": "-0.001034",
-                        "nextFundingTime": "1672387200000",
-                        "predictedDeliveryPrice": "",
-                        "basisRate": "",
-                        "deliveryFeeRate": "",
-                        "deliveryTime": "0",
-                        "ask1Size": "1",
-                        "bid1Price": "16596.00",
-                        "ask1Price": "16597.50",
-                        "bid1Size": "1",
-                        "basis": ""
+                        "realisedPnl": "-71.28",
+                        "symbol": "BTCPERP",
+                        "side": "Buy",
+                        "markPrice": "16620",
+                        "size": "1.5",
+                        "createdTime": "1672214400000",
+                        "sessionAvgPrice": "16620"
                     }
                 ]
             },
             "retExtInfo": {},
-            "time": 1672376496682
+            "time": 1672284883480
         }"#;
-        let message: Resp<Ticker> = deserialize_str(json).unwrap();
-        let expected = Resp {
-            ret_code: 0,
-            ret_msg: String::from("OK"),
-            result: Ticker::Inverse {
-                list: vec![LinearInverseTicker {
-                    symbol: String::from("BTCUSD"),
-                    last_price: dec!(16597.00),
-                    mark_price: dec!(16596.00),
-                    index_price: dec!(16598.54),
-                    prev_price24h: dec!(16464.50),
-                    price24h_pcnt: dec!(0.008047),
-                    high_price24h: dec!(30912.50),
-                    low_price24h: dec!(15700.00),
-                    prev_price1h: dec!(16595.50),
-                    open_interest: dec!(373504107),
-                    open_interest_value: dec!(22505.67),
-                    turnover24h: dec!(2352.94950046),
-                    volume24h: dec!(49337318),
-                    funding_rate: Some(dec!(-0.001034)),
-                    next_funding_time: 1672387200000,
-                    predicted_delivery_price: None,
-                    basis_rate: None,
-                    basis: None,
-                    delivery_fee_rate: None,
-                    delivery_time: Some(0),
-                    bid1_price: dec!(16596.00),
-                    bid1_size: dec!(1),
-                    ask1_price: dec!(16597.50),
-                    ask1_size: dec!(1),
-                    pre_open_price: None,
-                    pre_qty: None,
-                    cur_pre_listing_phase: None,
-                }],
+        let message: Resp<CursorPagination<SettlementRecord>> = deserialize_str(json).unwrap();
+        let expected = CursorPagination {
+            category: Category::Linear,
+            next_page_cursor: Some(String::from("116952%3A1%2C116952%3A1")),
+            list: vec![SettlementRecord {
+                symbol: String::from("BTCPERP"),
+                side: Side::Buy,
+                size: dec!(1.5),
+                session_avg_price: dec!(16620),
+                mark_price: dec!(16620),
+                realised_pnl: dec!(-71.28),
+                created_time: 1672214400000,
+            }],
+        };
+        assert_eq!(message.result, expected);
+    }
+
+    #[test]
+    fn serialize_create_sub_api_key_params() {
+        let params = CreateSubApiKeyParams {
+            subuid: 53888000,
+            note: Some(String::from("testxxx")),
+            read_only: false,
+            ips: None,
+            permissions: ApiKeyPermissions {
+                contract_trade: Some(vec![String::from("Order"), String::from("Position")]),
+                wallet: Some(vec![String::from("AccountTransfer")]),
+                ..Default::default()
+            },
+        };
+        let expected = r#"{"subuid":53888000,"note":"testxxx","readOnly":0,"permissions":{"ContractTrade":["Order","Position"],"Wallet":["AccountTransfer"]}}"#;
+
+        let json = serde_json::to_string(&params).unwrap();
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn deserialize_response_sub_api_key() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "",
+            "result": {
+                "id": "16651283",
+                "note": "testxxx",
+                "apiKey": "xxxxx",
+                "readOnly": 0,
+                "secret": "xxxxxxxx",
+                "permissions": {
+                    "ContractTrade": ["Order", "Position"],
+                    "Wallet": ["AccountTransfer"]
+                }
             },
-            time: 1672376496682,
-            ret_ext_info: RetExtInfo {},
-        };
-        assert_eq!(message, expected);
+            "retExtInfo": {},
+            "time": 1676430005459
+        }"#;
+        let message: Resp<SubApiKey> = deserialize_str(json).unwrap();
+
+        let secret = message.result.secret.as_ref().map(|secret| secret.expose());
+        assert_eq!(secret, Some("xxxxxxxx"));
+        assert!(!format!("{:?}", message.result).contains("xxxxxxxx"));
     }
 
     #[test]
-    fn deserialize_response_trad_spot() {
+    fn deserialize_response_sub_members() {
         let json = r#"{
             "retCode": 0,
-            "retMsg": "OK",
+            "retMsg": "",
             "result": {
-                "category": "spot",
-                "list": [
+                "subMembers": [
                     {
-                        "execId": "2100000000007764263",
-                        "symbol": "BTCUSDT",
-                        "price": "16618.49",
-                        "size": "0.00012",
-                        "side": "Buy",
-                        "time": "1672052955758",
-                        "isBlockTrade": false,
-                        "isRPITrade": true
+                        "uid": "53888001",
+                        "username": "xxx12",
+                        "memberType": 1,
+                        "status": 1,
+                        "remark": "",
+                        "accountMode": 5
                     }
                 ]
             },
             "retExtInfo": {},
-            "time": 1672053054358
+            "time": 1676430318405
         }"#;
-        let message: Resp<Trade> = deserialize_str(json).unwrap();
-        let expected = Resp {
-            ret_code: 0,
-            ret_msg: String::from("OK"),
-            result: Trade::Spot {
-                list: vec![InverseLinearSpotTrade {
-                    exec_id: String::from("2100000000007764263"),
-                    symbol: String::from("BTCUSDT"),
-                    price: dec!(16618.49),
-                    size: dec!(0.00012),
-                    side: Side::Buy,
-                    time: 1672052955758,
-                    is_block_trade: false,
-                    is_rpi_trade: true,
-                }],
-            },
-            time: 1672053054358,
-            ret_ext_info: RetExtInfo {},
+        let message: Resp<SubMembers> = deserialize_str(json).unwrap();
+        let expected = SubMembers {
+            sub_members: vec![SubMember {
+                uid: String::from("53888001"),
+                username: String::from("xxx12"),
+                member_type: SubMemberType::Normal,
+                status: SubMemberStatus::Normal,
+                account_mode: Some(UnifiedMarginStatus::UnifiedTradingAccount2),
+                remark: String::new(),
+            }],
         };
-        assert_eq!(message, expected);
+        assert_eq!(message.result, expected);
     }
 
     #[test]
-    fn deserialize_response_get_open_closed_orders_linear() {
+    fn deserialize_response_leveraged_token_orders() {
         let json = r#"{
             "retCode": 0,
             "retMsg": "OK",
             "result": {
                 "list": [
                     {
-                        "orderId": "fd4300ae-7847-404e-b947-b46980a4d140",
-                        "orderLinkId": "test-000005",
-                        "blockTradeId": "",
-                        "symbol": "ETHUSDT",
-                        "price": "1600.00",
-                        "qty": "0.10",
-                        "side": "Buy",
-                        "isLeverage": "",
-                        "positionIdx": 1,
-                        "orderStatus": "New",
-                        "cancelType": "UNKNOWN",
-                        "rejectReason": "EC_NoError",
-                        "avgPrice": "0",
-                        "leavesQty": "0.10",
-                        "leavesValue": "160",
-                        "cumExecQty": "0.00",
-                        "cumExecValue": "0",
-                        "cumExecFee": "0",
-                        "timeInForce": "GTC",
-                        "orderType": "Limit",
-                        "stopOrderType": "UNKNOWN",
-                        "orderIv": "",
-                        "triggerPrice": "0.00",
-                        "takeProfit": "2500.00",
-                        "stopLoss": "1500.00",
-                        "tpTriggerBy": "LastPrice",
-                        "slTriggerBy": "LastPrice",
-                        "triggerDirection": 0,
-                        "triggerBy": "UNKNOWN",
-                        "lastPriceOnCreated": "",
-                        "reduceOnly": false,
-                        "closeOnTrigger": false,
-                        "smpType": "None",
-                        "smpGroup": 0,
-                        "smpOrderId": "",
-                        "tpslMode": "Full",
-                        "tpLimitPrice": "",
-                        "slLimitPrice": "",
-                        "placeType": "",
-                        "createdTime": "1684738540559",
-                        "updatedTime": "1684738540561"
+                        "ltCoin": "BTC3L",
+                        "orderId": "1672203950044",
+                        "ltOrderType": 1,
+                        "orderTime": 1672203950044,
+                        "updateTime": 1672203950044,
+                        "ltOrderStatus": "1",
+                        "fee": "0.0001",
+                        "amount": "10",
+                        "value": "1.345",
+                        "valueCoin": "USDT",
+                        "serialNo": ""
                     }
-                ],
-                "nextPageCursor": "page_args%3Dfd4300ae-7847-404e-b947-b46980a4d140%26symbol%3D6%26",
-                "category": "linear"
+                ]
             },
             "retExtInfo": {},
-            "time": 1684765770483
+            "time": 1672204039225
         }"#;
-        let message: Resp<CursorPagination<Order>> = deserialize_str(json).unwrap();
-        let expected = Resp {
-            ret_code: 0,
-            ret_msg: String::from("OK"),
-            result: CursorPagination {
-                category: Category::Linear,
-                next_page_cursor: Some(String::from(
-                    "page_args%3Dfd4300ae-7847-404e-b947-b46980a4d140%26symbol%3D6%26",
-                )),
-                list: vec![Order {
-                    order_id: String::from("fd4300ae-7847-404e-b947-b46980a4d140"),
-                    order_link_id: Some(String::from("test-000005")),
-                    block_trade_id: None,
-                    symbol: String::from("ETHUSDT"),
-                    price: dec!(1600.00),
-                    qty: dec!(0.10),
-                    side: Side::Buy,
-                    is_leverage: None,
-                    position_idx: PositionIdx::Buy,
-                    order_status: OrderStatus::New,
-                    create_type: None,
-                    cancel_type: CancelType::UNKNOWN,
-                    reject_reason: RejectReason::EcNoError,
-                    avg_price: Some(dec!(0.0)),
-                    leaves_qty: dec!(0.10),
-                    leaves_value: dec!(160),
-                    cum_exec_qty: dec!(0.00),
-                    cum_exec_value: dec!(0),
-                    cum_exec_fee: dec!(0),
-                    time_in_force: TimeInForce::GTC,
-                    order_type: OrderType::Limit,
-                    stop_order_type: Some(StopOrderType::UNKNOWN),
-                    order_iv: None,
-                    market_unit: None,
-                    trigger_price: Some(dec!(0.00)),
-                    take_profit: Some(dec!(2500.00)),
-                    stop_loss: Some(dec!(1500.00)),
-                    tpsl_mode: Some(TpslMode::Full),
-                    oco_trigger_by: None,
-                    tp_limit_price: None,
-                    sl_limit_price: None,
-                    tp_trigger_by: Some(TriggerBy::LastPrice),
-                    sl_trigger_by: Some(TriggerBy::LastPrice),
-                    trigger_direction: TriggerDirection::UNKNOWN,
-                    trigger_by: TriggerBy::UNKNOWN,
-                    last_price_on_created: None,
-                    base_price: None,
-                    reduce_only: false,
-                    close_on_trigger: false,
-                    place_type: None,
-                    smp_type: SmpType::None,
-                    smp_group: 0,
-                    smp_order_id: None,
-                    created_time: 1684738540559,
-                    updated_time: 1684738540561,
-                }],
-            },
-            time: 1684765770483,
-            ret_ext_info: RetExtInfo {},
+        let message: Resp<List<LeveragedTokenOrder>> = deserialize_str(json).unwrap();
+        let expected = List {
+            category: None,
+            next_page_cursor: None,
+            list: vec![LeveragedTokenOrder {
+                lt_coin: String::from("BTC3L"),
+                order_id: String::from("1672203950044"),
+                lt_order_type: LtOrderType::Purchase,
+                order_time: 1672203950044,
+                update_time: 1672203950044,
+                lt_order_status: LtOrderStatus::Completed,
+                fee: dec!(0.0001),
+                amount: dec!(10),
+                value: dec!(1.345),
+                value_coin: String::from("USDT"),
+                serial_no: None,
+            }],
         };
-        assert_eq!(message, expected);
+        assert_eq!(message.result, expected);
     }
 
     #[test]
-    fn deserialize_response_get_position_info_inverse() {
+    fn serialize_spot_margin_params() {
+        let params = SwitchSpotMarginModeParams {
+            spot_margin_mode: true,
+        };
+        let json = serde_json::to_string(&params).unwrap();
+        assert_eq!(json, r#"{"spotMarginMode":"1"}"#);
+
+        let params = SetPledgeTokenParams {
+            coin: String::from("BTC"),
+            pledge: false,
+        };
+        let json = serde_json::to_string(&params).unwrap();
+        assert_eq!(json, r#"{"coin":"BTC","collateralSwitch":"OFF"}"#);
+    }
+
+    #[test]
+    fn deserialize_response_vip_margin_data() {
         let json = r#"{
             "retCode": 0,
-            "retMsg": "OK",
+            "retMsg": "success",
             "result": {
-                "list": [
+                "vipCoinList": [
                     {
-                        "positionIdx": 0,
-                        "riskId": 1,
-                        "riskLimitValue": "150",
-                        "symbol": "BTCUSD",
-                        "side": "Sell",
-                        "size": "300",
-                        "avgPrice": "27464.50441675",
-                        "positionValue": "0.01092319",
-                        "tradeMode": 0,
-                        "positionStatus": "Normal",
-                        "autoAddMargin": 1,
-                        "adlRankIndicator": 2,
-                        "leverage": "10",
-                        "positionBalance": "0.00139186",
-                        "markPrice": "28224.50",
-                        "liqPrice": "",
-                        "bustPrice": "999999.00",
-                        "positionMM": "0.0000015",
-                        "positionIM": "0.00010923",
-                        "tpslMode": "Full",
-                        "takeProfit": "0.00",
-                        "stopLoss": "0.00",
-                        "trailingStop": "0.00",
-                        "unrealisedPnl": "-0.00029413",
-                        "curRealisedPnl": "0.00013123",
-                        "cumRealisedPnl": "-0.00096902",
-                        "seq": 5723621632,
-                        "isReduceOnly": false,
-                        "mmrSysUpdatedTime": "",
-                        "leverageSysUpdatedTime": "",
-                        "sessionAvgPrice": "",
-                        "createdTime": "1676538056258",
-                        "updatedTime": "1697673600012"
+                        "list": [
+                            {
+                                "borrowable": true,
+                                "collateralRatio": "0.95",
+                                "currency": "BTC",
+                                "hourlyBorrowRate": "0.0000015021220000",
+                                "liquidationOrder": 11,
+                                "marginCollateral": true,
+                                "maxBorrowingAmount": "3"
+                            }
+                        ],
+                        "vipLevel": "No VIP"
                     }
-                ],
-                "nextPageCursor": "",
-                "category": "inverse"
+                ]
             },
             "retExtInfo": {},
-            "time": 1697684980172
+            "time": 1692696111853
         }"#;
-        let message: Resp<CursorPagination<Position>> = deserialize_str(json).unwrap();
-        let expected = Resp {
-            ret_code: 0,
-            ret_msg: String::from("OK"),
-            result: CursorPagination {
-                category: Category::Inverse,
-                next_page_cursor: None,
-                list: vec![Position {
-                    position_idx: PositionIdx::OneWay,
-                    risk_id: 1,
-                    risk_limit_value: dec!(150),
-                    symbol: String::from("BTCUSD"),
-                    side: Some(Side::Sell),
-                    size: dec!(300),
-                    avg_price: dec!(27464.50441675),
-                    position_value: Some(dec!(0.01092319)),
-                    trade_mode: TradeMode::CrossMargin,
-                    auto_add_margin: true,
-                    position_status: PositionStatus::Normal,
-                    leverage: dec!(10),
-                    mark_price: dec!(28224.50),
-                    liq_price: None,
-                    bust_price: Some(dec!(999999.00)),
-                    position_im: dec!(0.00010923),
-                    position_mm: dec!(0.0000015),
-                    position_balance: dec!(0.00139186),
-                    take_profit: Some(dec!(0.00)),
-                    stop_loss: Some(dec!(0.00)),
-                    trailing_stop: dec!(0.00),
-                    session_avg_price: None,
-                    delta: None,
-                    gamma: None,
-                    vega: None,
-                    theta: None,
-                    unrealised_pnl: Some(dec!(-0.00029413)),
-                    cur_realised_pnl: dec!(0.00013123),
-                    cum_realised_pnl: dec!(-0.00096902),
-                    adl_rank_indicator: AdlRankIndicator::Two,
-                    created_time: 1676538056258,
-                    updated_time: 1697673600012,
-                    seq: 5723621632,
-                    is_reduce_only: false,
-                    mmr_sys_updated_time: None,
-                    leverage_sys_updated_time: None,
-                    tpsl_mode: TpslMode::Full,
+        let message: Resp<VipMarginData> = deserialize_str(json).unwrap();
+        let expected = VipMarginData {
+            vip_coin_list: vec![VipMarginCoins {
+                list: vec![VipMarginCoin {
+                    borrowable: true,
+                    collateral_ratio: dec!(0.95),
+                    currency: String::from("BTC"),
+                    hourly_borrow_rate: dec!(0.0000015021220000),
+                    liquidation_order: 11,
+                    margin_collateral: true,
+                    max_borrowing_amount: dec!(3),
                 }],
+                vip_level: VipLevel::NoVIP,
+            }],
+        };
+        assert_eq!(message.result, expected);
+    }
+
+    #[test]
+    fn deserialize_response_convert_quote() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "ok",
+            "result": {
+                "quoteTxId": "10100108106409343501030232064",
+                "exchangeRate": "0.000015330836780000",
+                "fromCoin": "USDT",
+                "fromCoinType": "crypto",
+                "toCoin": "BTC",
+                "toCoinType": "crypto",
+                "fromAmount": "0.1",
+                "toAmount": "0.000001533083678",
+                "expiredTime": "1720071899789",
+                "requestId": ""
             },
-            time: 1697684980172,
-            ret_ext_info: RetExtInfo {},
+            "retExtInfo": {},
+            "time": 1720071884789
+        }"#;
+        let message: Resp<ConvertQuote> = deserialize_str(json).unwrap();
+        let expected = ConvertQuote {
+            quote_tx_id: String::from("10100108106409343501030232064"),
+            exchange_rate: dec!(0.000015330836780000),
+            from_coin: String::from("USDT"),
+            from_coin_type: String::from("crypto"),
+            to_coin: String::from("BTC"),
+            to_coin_type: String::from("crypto"),
+            from_amount: dec!(0.1),
+            to_amount: dec!(0.000001533083678),
+            expired_time: 1720071899789,
+            request_id: None,
         };
-        assert_eq!(message, expected);
+        assert_eq!(message.result, expected);
+        assert!(message.result.is_valid(message.time));
+        assert_eq!(message.result.time_left(message.time), 15000);
+        assert!(!message.result.is_valid(1720071899789));
+        assert_eq!(message.result.time_left(1720071999789), 0);
+    }
+
+    #[test]
+    fn build_get_open_closed_orders_params() {
+        let params = GetOpenClosedOrdersParams::linear("BTCUSDT").limit(10);
+
+        let query = serde_urlencoded::to_string(&params).unwrap();
+
+        assert_eq!(query, "category=linear&symbol=BTCUSDT&limit=10");
     }
 }
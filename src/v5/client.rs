@@ -1,15 +1,53 @@
-use reqwest::{self, Method, RequestBuilder, header::HeaderMap};
-use serde_json::{Value, from_value};
+use reqwest::{
+    self, Method, RequestBuilder,
+    header::{CONTENT_TYPE, HeaderMap},
+};
+use rust_decimal::Decimal;
 
 use crate::v5::{
-    APIErrorResponse, GetPositionInfoParams, Position, crypto::Signer, serde::deserialize_str,
+    APIErrorResponse, GetPositionInfoParams, Position,
+    crypto::{Signer, TimeSync, timestamp},
+    pool::RateLimiter,
+    serde::deserialize_str,
 };
 
 use super::{
-    CursorPagination, Error, GetInstrumentsInfoParams, GetKLinesParams, GetOpenClosedOrdersParams,
-    GetTickersParams, GetTradesParams, Headers, InstrumentsInfo, KLine, Order, Resp, Response,
-    ServerTime, Ticker, Trade, crypto::SensitiveString, url::*,
+    AccountCoinBalance, AccountInfo, AccountType, AccountWallet, AllCoinsBalance,
+    AllowedDepositCoins, Announcements, AssetInfo, BorrowHistory, CancelWithdrawalParams,
+    CancelWithdrawalResult, ClosedPnl, CoinsInfo, CollateralCoin, ConfirmQuoteParams,
+    ConfirmQuoteResult, Convert, ConvertCoins, ConvertQuote, ConvertStatusResult,
+    CreateSubApiKeyParams, CreateSubMemberParams, CursorPagination, DeleteSubApiKeyParams,
+    DeliveryPrice, DeliveryRecord, DepositAddresses, DepositRecords, EmptyResult,
+    EnableSubMemberTransferParams, Error, ExchangeRecords, Execution, FeeRate,
+    FreezeSubMemberParams, FundingRate, GetAllCoinsBalanceParams, GetAllowedDepositCoinsParams,
+    GetAnnouncementsParams, GetAssetInfoParams, GetBorrowHistoryParams, GetClosedPnlParams,
+    GetCoinBalanceParams, GetCoinInfoParams, GetCollateralInfoParams, GetConvertCoinsParams,
+    GetConvertHistoryParams, GetConvertStatusParams, GetDeliveryPriceParams,
+    GetDeliveryRecordsParams, GetDepositAddressParams, GetDepositRecordsParams,
+    GetExchangeRecordsParams, GetExecutionsParams, GetFeeRatesParams, GetFundingRateHistoryParams,
+    GetHistoricalVolatilityParams, GetInstrumentsInfoParams, GetInsuranceParams, GetKLinesParams,
+    GetLeveragedTokenInfoParams, GetLeveragedTokenMarketParams, GetLeveragedTokenOrdersParams,
+    GetLongShortRatioParams, GetOpenClosedOrdersParams, GetOpenInterestParams, GetRiskLimitParams,
+    GetSettlementRecordsParams, GetSubDepositAddressParams, GetSubDepositRecordsParams,
+    GetSystemStatusParams, GetTickersParams, GetTradesParams, GetTransactionLogParams,
+    GetTransferRecordsParams, GetTransferableCoinsParams, GetVipMarginDataParams,
+    GetWalletBalanceParams, GetWithdrawalRecordsParams, Headers, HistoricalVolatility,
+    InstrumentsInfo, Insurance, InterTransferParams, InterTransferRecord, KLine,
+    LeveragedTokenInfo, LeveragedTokenMarket, LeveragedTokenOrder, LeveragedTokenPurchase,
+    LeveragedTokenRedemption, List, LongShortRatio, Maintenance, ModifyOutcome, OpenInterest,
+    Order, OutgoingMessage, PurchaseLeveragedTokenParams, RedeemLeveragedTokenParams,
+    RequestDemoFundsParams, RequestQuoteParams, Resp, Response, RiskLimit, ServerTime,
+    SetAutoAddMarginParams, SetDcpParams, SetLeverageParams, SetMarginModeParams,
+    SetMarginModeResult, SetPledgeTokenParams, SetSpotMarginLeverageParams, SetTradingStopParams,
+    SettlementRecord, SpotBorrowCheck, SpotBorrowCheckParams, SpotMarginMode, SpotMarginState,
+    SubApiKey, SubDepositAddress, SubMember, SubMemberTransferList, SubMembers,
+    SwitchIsolatedParams, SwitchPositionModeParams, SwitchSpotMarginModeParams, SystemState,
+    Ticker, Trade, TransactionLog, TransferResult, UniversalTransferParams,
+    UniversalTransferRecord, UpdateSubApiKeyParams, UpgradeToUtaResult, Validate, VipMarginData,
+    WithdrawalRecords, crypto::SensitiveString, url::*,
 };
+#[cfg(feature = "withdraw")]
+use super::{CreateWithdrawalParams, WithdrawPolicy, WithdrawalId};
 
 pub struct ClientConfig {
     pub base_url: String,
@@ -25,6 +63,10 @@ pub struct Client {
     base_url: String,
     headers: HeaderMap,
     signer: Option<Signer>,
+    http: reqwest::Client,
+    rate_limiter: Option<RateLimiter>,
+    #[cfg(feature = "withdraw")]
+    withdraw_policy: Option<WithdrawPolicy>,
 }
 
 impl Client {
@@ -53,19 +95,118 @@ impl Client {
             base_url: cfg.base_url,
             headers,
             signer,
+            http: reqwest::Client::new(),
+            rate_limiter: None,
+            #[cfg(feature = "withdraw")]
+            withdraw_policy: None,
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Send requests through the given HTTP client and its connection pool.
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Sign requests with the shared clock offset.
+    pub fn with_time_sync(mut self, time_sync: TimeSync) -> Self {
+        self.signer = self.signer.map(|signer| signer.with_time_sync(time_sync));
+        self
+    }
+
+    /// Wait for the rate limiter before every request.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    pub(crate) fn signer(&self) -> Option<&Signer> {
+        self.signer.as_ref()
+    }
+
+    /// Authentication message of a private stream, valid for 10 seconds.
+    pub fn stream_auth(&self, req_id: Option<String>) -> Result<OutgoingMessage, Error> {
+        match self.signer.as_ref() {
+            Some(signer) => Ok(signer.stream_auth(req_id, 10_000)),
+            None => Err(Error::Msg(String::from("api key and secret are required"))),
+        }
+    }
+
+    /// Measure the offset between the local clock and the server clock.
+    /// Return the offset in milliseconds.
+    pub async fn server_time_offset(&self) -> Result<i64, Error> {
+        let sent_at = timestamp();
+        let response = self.get_server_time().await?;
+        let received_at = timestamp();
+
+        let server_time = (response.result.time_nano / 1_000_000) as i128;
+        let local_time = ((sent_at + received_at) / 2) as i128;
+        Ok((server_time - local_time) as i64)
+    }
+
+    /// Measure the offset between the local clock and the server clock and use it for signing.
+    /// Return the offset in milliseconds.
+    pub async fn sync_time(&self) -> Result<i64, Error> {
+        let offset = self.server_time_offset().await?;
+        if let Some(signer) = self.signer.as_ref() {
+            signer.time_sync().set_offset(offset);
         }
+        Ok(offset)
     }
 
-    fn get_signed_headers(&self, s: &str) -> HeaderMap {
+    /// Rules checked by `create_withdrawal`. Without a policy every withdrawal is rejected.
+    #[cfg(feature = "withdraw")]
+    pub fn with_withdraw_policy(mut self, policy: WithdrawPolicy) -> Self {
+        self.withdraw_policy = Some(policy);
+        self
+    }
+
+    fn get_signed_headers(&self, s: &str) -> Result<HeaderMap, Error> {
+        let Some(signer) = self.signer.as_ref() else {
+            return Err(Error::Msg(String::from("api key and secret are required")));
+        };
         let mut headers = self.headers.clone();
 
-        let (signature, timestamp) = self.signer.as_ref().unwrap().sign(s);
+        let (signature, timestamp) = signer.sign(s);
         let signature = signature.parse().unwrap();
         headers.append(HEADER_X_BAPI_SIGN, signature);
         let timestamp = timestamp.parse().unwrap();
         headers.append(HEADER_X_BAPI_TIMESTAMP, timestamp);
 
-        headers
+        Ok(headers)
+    }
+
+    async fn send<T>(&self, request: RequestBuilder) -> Result<Response<T>, Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.acquire().await;
+        send(request).await
+    }
+
+    /// Sign the payload (query string or JSON body) only after the rate limiter let the request through,
+    /// so that a request held back by the limiter is not sent with a stale timestamp.
+    async fn send_signed<T>(
+        &self,
+        request: RequestBuilder,
+        payload: &str,
+    ) -> Result<Response<T>, Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.acquire().await;
+        let headers = self.get_signed_headers(payload)?;
+        send(request.headers(headers)).await
+    }
+
+    async fn acquire(&self) {
+        if let Some(rate_limiter) = self.rate_limiter.as_ref() {
+            rate_limiter.acquire().await;
+        }
     }
 }
 
@@ -74,20 +215,22 @@ impl Client {
     pub async fn get_server_time(&self) -> Result<Response<ServerTime>, Error> {
         let url = format!("{}{}", self.base_url, Path::MarketServerTime);
 
-        let client = reqwest::Client::builder().build()?;
+        let client = &self.http;
         let request = client.request(Method::GET, url);
 
-        let response = send(request).await?;
+        let response = self.send(request).await?;
         Ok(response)
     }
 
     pub async fn get_kline(&self, params: GetKLinesParams) -> Result<Response<KLine>, Error> {
+        params.validate()?;
+
         let url = format!("{}{}", self.base_url, Path::MarketKline);
 
-        let client = reqwest::Client::builder().build()?;
+        let client = &self.http;
         let request = client.request(Method::GET, url).query(&params);
 
-        let response = send(request).await?;
+        let response = self.send(request).await?;
         Ok(response)
     }
 
@@ -95,12 +238,14 @@ impl Client {
     /// Query for the latest price snapshot, best bid/ask price, and trading volume in the last 24 hours.
     /// If category=option, symbol or baseCoin must be passed.
     pub async fn get_tickers(&self, params: GetTickersParams) -> Result<Response<Ticker>, Error> {
+        params.validate()?;
+
         let url = format!("{}{}", self.base_url, Path::MarketTickers);
 
-        let client = reqwest::Client::builder().build()?;
+        let client = &self.http;
         let request = client.request(Method::GET, url).query(&params);
 
-        let response = send(request).await?;
+        let response = self.send(request).await?;
         Ok(response)
     }
 
@@ -108,12 +253,135 @@ impl Client {
         &self,
         params: GetInstrumentsInfoParams,
     ) -> Result<Response<InstrumentsInfo>, Error> {
+        params.validate()?;
+
         let url = format!("{}{}", self.base_url, Path::MarketInstrumentsInfo);
 
-        let client = reqwest::Client::builder().build()?;
+        let client = &self.http;
+        let request = client.request(Method::GET, url).query(&params);
+
+        let response = self.send(request).await?;
+        Ok(response)
+    }
+
+    /// Get Funding Rate History
+    /// Query for historical funding rates. Each symbol has a different funding interval.
+    /// For example, if the interval is 8 hours and the current time is UTC 12, then it returns the last funding rate, which settled at UTC 8.
+    pub async fn get_funding_rate_history(
+        &self,
+        params: GetFundingRateHistoryParams,
+    ) -> Result<Response<List<FundingRate>>, Error> {
+        params.validate()?;
+
+        let url = format!("{}{}", self.base_url, Path::MarketFundingHistory);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url).query(&params);
+
+        let response = self.send(request).await?;
+        Ok(response)
+    }
+
+    /// Get Open Interest
+    /// Get the open interest of each symbol.
+    pub async fn get_open_interest(
+        &self,
+        params: GetOpenInterestParams,
+    ) -> Result<Response<CursorPagination<OpenInterest>>, Error> {
+        params.validate()?;
+
+        let url = format!("{}{}", self.base_url, Path::MarketOpenInterest);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url).query(&params);
+
+        let response = self.send(request).await?;
+        Ok(response)
+    }
+
+    /// Get Historical Volatility
+    /// Query option historical volatility.
+    pub async fn get_historical_volatility(
+        &self,
+        params: GetHistoricalVolatilityParams,
+    ) -> Result<Response<Vec<HistoricalVolatility>>, Error> {
+        params.validate()?;
+
+        let url = format!("{}{}", self.base_url, Path::MarketHistoricalVolatility);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url).query(&params);
+
+        let response = self.send(request).await?;
+        Ok(response)
+    }
+
+    /// Get Insurance Pool
+    /// Query for Bybit insurance pool data (BTC/USDT/USDC etc). The data is updated every 24 hours.
+    pub async fn get_insurance(
+        &self,
+        params: GetInsuranceParams,
+    ) -> Result<Response<Insurance>, Error> {
+        params.validate()?;
+
+        let url = format!("{}{}", self.base_url, Path::MarketInsurance);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url).query(&params);
+
+        let response = self.send(request).await?;
+        Ok(response)
+    }
+
+    /// Get Risk Limit
+    /// Query for the risk limit tiers of a symbol.
+    /// The table is sorted from the lowest to the highest risk limit value.
+    pub async fn get_risk_limit(
+        &self,
+        params: GetRiskLimitParams,
+    ) -> Result<Response<CursorPagination<RiskLimit>>, Error> {
+        params.validate()?;
+
+        let url = format!("{}{}", self.base_url, Path::MarketRiskLimit);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url).query(&params);
+
+        let response = self.send(request).await?;
+        Ok(response)
+    }
+
+    /// Get Delivery Price
+    /// Get the delivery price of expiring futures and options.
+    pub async fn get_delivery_price(
+        &self,
+        params: GetDeliveryPriceParams,
+    ) -> Result<Response<CursorPagination<DeliveryPrice>>, Error> {
+        params.validate()?;
+
+        let url = format!("{}{}", self.base_url, Path::MarketDeliveryPrice);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url).query(&params);
+
+        let response = self.send(request).await?;
+        Ok(response)
+    }
+
+    /// Get Long Short Ratio
+    /// This refers to the net long and short positions as percentages of all position holders during the selected time.
+    pub async fn get_long_short_ratio(
+        &self,
+        params: GetLongShortRatioParams,
+    ) -> Result<Response<List<LongShortRatio>>, Error> {
+        params.validate()?;
+
+        let url = format!("{}{}", self.base_url, Path::MarketAccountRatio);
+
+        let client = &self.http;
         let request = client.request(Method::GET, url).query(&params);
 
-        let response = send(request).await?;
+        let response = self.send(request).await?;
         Ok(response)
     }
 
@@ -121,12 +389,66 @@ impl Client {
         &self,
         params: GetTradesParams,
     ) -> Result<Response<Trade>, Error> {
+        params.validate()?;
+
         let url = format!("{}{}", self.base_url, Path::MarketRecentTrade);
 
-        let client = reqwest::Client::builder().build()?;
+        let client = &self.http;
+        let request = client.request(Method::GET, url).query(&params);
+
+        let response = self.send(request).await?;
+        Ok(response)
+    }
+}
+
+// System.
+impl Client {
+    /// Get System Status
+    /// Get the system status when there is a platform maintenance or service incident.
+    pub async fn get_system_status(
+        &self,
+        params: GetSystemStatusParams,
+    ) -> Result<Response<List<Maintenance>>, Error> {
+        params.validate()?;
+
+        let url = format!("{}{}", self.base_url, Path::SystemStatus);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url).query(&params);
+
+        let response = self.send(request).await?;
+        Ok(response)
+    }
+
+    /// Check whether a platform maintenance is in progress right now.
+    pub async fn is_under_maintenance(&self) -> Result<bool, Error> {
+        let params = GetSystemStatusParams::new().state(SystemState::Ongoing);
+        let response = self.get_system_status(params).await?;
+        let ongoing = response
+            .result
+            .list
+            .iter()
+            .any(|maintenance| maintenance.state == SystemState::Ongoing);
+        Ok(ongoing)
+    }
+}
+
+// Announcement.
+impl Client {
+    /// Get Announcement
+    /// Query the platform announcements, e.g. new listings, delistings, maintenance updates.
+    pub async fn get_announcements(
+        &self,
+        params: GetAnnouncementsParams,
+    ) -> Result<Response<Announcements>, Error> {
+        params.validate()?;
+
+        let url = format!("{}{}", self.base_url, Path::AnnouncementsIndex);
+
+        let client = &self.http;
         let request = client.request(Method::GET, url).query(&params);
 
-        let response = send(request).await?;
+        let response = self.send(request).await?;
         Ok(response)
     }
 }
@@ -150,14 +472,51 @@ impl Client {
         &self,
         params: GetOpenClosedOrdersParams,
     ) -> Result<Response<CursorPagination<Order>>, Error> {
+        params.validate()?;
+
         let query = serde_urlencoded::to_string(&params)?;
         let url = format!("{}{}?{query}", self.base_url, Path::OrderRealtime);
-        let headers = self.get_signed_headers(&query);
 
-        let client = reqwest::Client::builder().build()?;
-        let request = client.request(Method::GET, url).headers(headers);
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Borrow Quota (Spot)
+    /// Query the available balance for Spot trading and Margin trading.
+    pub async fn spot_borrow_check(
+        &self,
+        params: SpotBorrowCheckParams,
+    ) -> Result<Response<SpotBorrowCheck>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!("{}{}?{query}", self.base_url, Path::OrderSpotBorrowCheck);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Set Disconnect Cancel All
+    /// When the connection of the private stream is lost for longer than the window, all open orders of the product are cancelled.
+    pub async fn set_dcp(&self, params: SetDcpParams) -> Result<Response<EmptyResult>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::OrderDisconnectedCancelAll);
 
-        let response = send(request).await?;
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
         Ok(response)
     }
 }
@@ -178,24 +537,1263 @@ impl Client {
         &self,
         params: GetPositionInfoParams,
     ) -> Result<Response<CursorPagination<Position>>, Error> {
+        params.validate()?;
+
         let query = serde_urlencoded::to_string(&params)?;
         let url = format!("{}{}?{query}", self.base_url, Path::PositionList);
-        let headers = self.get_signed_headers(&query);
 
-        let client = reqwest::Client::builder().build()?;
-        let request = client.request(Method::GET, url).headers(headers);
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Set Leverage
+    /// According to the risk limit, leverage can be set from 1 to the max leverage.
+    /// "Leverage not modified" is reported as `ModifyOutcome::NotModified`.
+    pub async fn set_leverage(&self, params: SetLeverageParams) -> Result<ModifyOutcome, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::PositionSetLeverage);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response: Result<Response<EmptyResult>, Error> = self.send_signed(request, &body).await;
+        modify_outcome(response)
+    }
+
+    /// Set Trading Stop
+    /// Set the take profit, stop loss or trailing stop for the position.
+    /// Passing these parameters will create conditional orders by the system internally. The system will cancel these orders if the position is closed, and adjust the qty according to the size of the open position.
+    pub async fn set_trading_stop(
+        &self,
+        params: SetTradingStopParams,
+    ) -> Result<ModifyOutcome, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::PositionTradingStop);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response: Result<Response<EmptyResult>, Error> = self.send_signed(request, &body).await;
+        modify_outcome(response)
+    }
+
+    /// Switch Cross/Isolated Margin
+    /// Select cross margin mode or isolated margin mode per symbol level.
+    /// Unified account is not applicable to this API, use `set_margin_mode` instead.
+    pub async fn switch_isolated(
+        &self,
+        params: SwitchIsolatedParams,
+    ) -> Result<ModifyOutcome, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::PositionSwitchIsolated);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response: Result<Response<EmptyResult>, Error> = self.send_signed(request, &body).await;
+        modify_outcome(response)
+    }
+
+    /// Switch Position Mode
+    /// It supports to switch the position mode for USDT perpetual and Inverse futures.
+    /// If you are in one-way Mode, you can only open one position on Buy or Sell side. If you are in hedge mode, you can open both Buy and Sell side positions simultaneously.
+    pub async fn switch_position_mode(
+        &self,
+        params: SwitchPositionModeParams,
+    ) -> Result<ModifyOutcome, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::PositionSwitchMode);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response: Result<Response<EmptyResult>, Error> = self.send_signed(request, &body).await;
+        modify_outcome(response)
+    }
+
+    /// Set Auto Add Margin
+    /// Turn on/off auto-add-margin for isolated margin position.
+    pub async fn set_auto_add_margin(
+        &self,
+        params: SetAutoAddMarginParams,
+    ) -> Result<ModifyOutcome, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::PositionSetAutoAddMargin);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response: Result<Response<EmptyResult>, Error> = self.send_signed(request, &body).await;
+        modify_outcome(response)
+    }
+
+    /// Get Closed PnL
+    /// Query user's closed profit and loss records.
+    /// The records are sorted by the createdTime from newest to oldest. Data older than two years are not returned.
+    pub async fn get_closed_pnl(
+        &self,
+        params: GetClosedPnlParams,
+    ) -> Result<Response<CursorPagination<ClosedPnl>>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!("{}{}?{query}", self.base_url, Path::PositionClosedPnl);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Trade History
+    /// Query users' execution records, sorted by execTime in descending order.
+    /// Response items will have sorting issues when 'execTime' is the same, it is recommended to sort according to execId+OrderId+leavesQty.
+    pub async fn get_executions(
+        &self,
+        params: GetExecutionsParams,
+    ) -> Result<Response<CursorPagination<Execution>>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!("{}{}?{query}", self.base_url, Path::ExecutionList);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+}
+
+// Account.
+impl Client {
+    /// Get Wallet Balance
+    /// Obtain wallet balance, query asset information of each currency. By default, currency information with assets or liabilities of 0 is not returned.
+    pub async fn get_wallet_balance(
+        &self,
+        params: GetWalletBalanceParams,
+    ) -> Result<Response<List<AccountWallet>>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!("{}{}?{query}", self.base_url, Path::AccountWalletBalance);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Account Info
+    /// Query the margin mode configuration of the account.
+    pub async fn get_account_info(&self) -> Result<Response<AccountInfo>, Error> {
+        let url = format!("{}{}", self.base_url, Path::AccountInfo);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, "").await?;
+        Ok(response)
+    }
+
+    /// Get Transaction Log
+    /// Query for transaction logs in your Unified account. It supports up to 2 years worth of data.
+    pub async fn get_transaction_log(
+        &self,
+        params: GetTransactionLogParams,
+    ) -> Result<Response<List<TransactionLog>>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!("{}{}?{query}", self.base_url, Path::AccountTransactionLog);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Borrow History
+    /// Get interest records, sorted in reverse order of creation time.
+    pub async fn get_borrow_history(
+        &self,
+        params: GetBorrowHistoryParams,
+    ) -> Result<Response<List<BorrowHistory>>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!("{}{}?{query}", self.base_url, Path::AccountBorrowHistory);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Collateral Info
+    /// Get the collateral information of the current unified margin account, including loan interest rate, loanable amount, collateral conversion rate, whether it can be mortgaged as margin, etc.
+    pub async fn get_collateral_info(
+        &self,
+        params: GetCollateralInfoParams,
+    ) -> Result<Response<List<CollateralCoin>>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!("{}{}?{query}", self.base_url, Path::AccountCollateralInfo);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Set Margin Mode
+    /// Default is regular margin mode.
+    /// Reasons why the mode could not be switched are returned in the result.
+    pub async fn set_margin_mode(
+        &self,
+        params: SetMarginModeParams,
+    ) -> Result<Response<SetMarginModeResult>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::AccountSetMarginMode);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Upgrade to Unified Trading Account
+    /// Upgrade Unified Account.
+    /// The upgrade process is asynchronous, check the status with `get_account_info` when PROCESS is returned.
+    pub async fn upgrade_to_uta(&self) -> Result<Response<UpgradeToUtaResult>, Error> {
+        let body = String::from("{}");
+        let url = format!("{}{}", self.base_url, Path::AccountUpgradeToUta);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Request Demo Trading Funds
+    /// Adjust the balance of a demo trading account. Available on `BASE_URL_API_DEMO_TRADING` only.
+    /// Frequency: 1 request per minute.
+    pub async fn request_demo_funds(
+        &self,
+        params: RequestDemoFundsParams,
+    ) -> Result<Response<EmptyResult>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!(
+            "{}{}",
+            self.base_url,
+            Path::AccountSetMarginModeDemoApplyMoney
+        );
 
-        let response = send(request).await?;
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Get Fee Rate
+    /// Get the trading fee rate.
+    pub async fn get_fee_rates(
+        &self,
+        params: GetFeeRatesParams,
+    ) -> Result<Response<List<FeeRate>>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!("{}{}?{query}", self.base_url, Path::AccountFeeRate);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
         Ok(response)
     }
 }
 
+// Asset.
+impl Client {
+    /// Get Delivery Record
+    /// Query delivery records of Inverse Futures, USDC Futures, USDT Futures and Options, sorted by deliveryTime in descending order.
+    pub async fn get_delivery_records(
+        &self,
+        params: GetDeliveryRecordsParams,
+    ) -> Result<Response<CursorPagination<DeliveryRecord>>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!("{}{}?{query}", self.base_url, Path::AssetDeliveryRecord);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get USDC Session Settlement
+    /// Query session settlement records of USDC perpetual and futures.
+    pub async fn get_settlement_records(
+        &self,
+        params: GetSettlementRecordsParams,
+    ) -> Result<Response<CursorPagination<SettlementRecord>>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!("{}{}?{query}", self.base_url, Path::AssetSettlementRecord);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Create Internal Transfer
+    /// Create the internal transfer between different account types under the same UID.
+    /// Repeating a request with the same transferId does not transfer twice.
+    pub async fn create_internal_transfer(
+        &self,
+        params: InterTransferParams,
+    ) -> Result<Response<TransferResult>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::AssetTransferInterTransfer);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Get Internal Transfer Records
+    /// Query the internal transfer records between different account types under the same UID.
+    pub async fn get_internal_transfer_records(
+        &self,
+        params: GetTransferRecordsParams,
+    ) -> Result<Response<List<InterTransferRecord>>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::AssetTransferQueryInterTransferList
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Create Universal Transfer
+    /// Transfer between sub-sub or main-sub. Use the master api key to transfer from or to any sub account; a sub api key can only transfer from itself.
+    /// Repeating a request with the same transferId does not transfer twice.
+    pub async fn create_universal_transfer(
+        &self,
+        params: UniversalTransferParams,
+    ) -> Result<Response<TransferResult>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::AssetTransferUniversalTransfer);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Get Universal Transfer Records
+    /// Query universal transfer records.
+    pub async fn get_universal_transfer_records(
+        &self,
+        params: GetTransferRecordsParams,
+    ) -> Result<Response<List<UniversalTransferRecord>>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::AssetTransferQueryUniversalTransferList
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Single Coin Balance
+    /// Query the balance of a specific coin in a specific account type. Supports querying sub UID's balance.
+    /// Also, you can check the transferable amount from master to sub account, sub to master account or sub to sub account, especially for user who has an institutional loan.
+    pub async fn get_coin_balance(
+        &self,
+        params: GetCoinBalanceParams,
+    ) -> Result<Response<AccountCoinBalance>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::AssetTransferQueryAccountCoinBalance
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Amount of a coin that can be transferred between two account types under the same UID.
+    pub async fn get_transferable_amount(
+        &self,
+        coin: impl Into<String>,
+        from_account_type: AccountType,
+        to_account_type: AccountType,
+    ) -> Result<Decimal, Error> {
+        let params =
+            GetCoinBalanceParams::new(from_account_type, coin).to_account_type(to_account_type);
+        let response = self.get_coin_balance(params).await?;
+        Ok(response.result.balance.transfer_balance)
+    }
+
+    /// Get All Coins Balance
+    /// You could get all coin balance of all account types under the master account, and sub account.
+    pub async fn get_all_coins_balance(
+        &self,
+        params: GetAllCoinsBalanceParams,
+    ) -> Result<Response<AllCoinsBalance>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::AssetTransferQueryAccountCoinsBalance
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Asset Info
+    /// Query Spot asset information. Applicable to classic account only.
+    pub async fn get_asset_info(
+        &self,
+        params: GetAssetInfoParams,
+    ) -> Result<Response<AssetInfo>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::AssetTransferQueryAssetInfo
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Transferable Coin
+    /// Query the transferable coin list between each account type.
+    pub async fn get_transferable_coins(
+        &self,
+        params: GetTransferableCoinsParams,
+    ) -> Result<Response<List<String>>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::AssetTransferQueryTransferCoinList
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Allowed Deposit Coin Info
+    /// Query allowed deposit coin information. To find out paired chain of coin, please refer coin info api.
+    pub async fn get_allowed_deposit_coins(
+        &self,
+        params: GetAllowedDepositCoinsParams,
+    ) -> Result<Response<AllowedDepositCoins>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::AssetDepositQueryAllowedList
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Deposit Records (on-chain)
+    /// Query deposit records.
+    pub async fn get_deposit_records(
+        &self,
+        params: GetDepositRecordsParams,
+    ) -> Result<Response<DepositRecords>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!("{}{}?{query}", self.base_url, Path::AssetDepositQueryRecord);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Sub Deposit Records (on-chain)
+    /// Query subaccount's deposit records by main UID's API key.
+    pub async fn get_sub_deposit_records(
+        &self,
+        params: GetSubDepositRecordsParams,
+    ) -> Result<Response<DepositRecords>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::AssetDepositQuerySubMemberRecord
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Master Deposit Address
+    /// Query the deposit address information of MASTER account.
+    pub async fn get_deposit_address(
+        &self,
+        params: GetDepositAddressParams,
+    ) -> Result<Response<DepositAddresses>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::AssetDepositQueryAddress
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Sub Deposit Address
+    /// Query the deposit address information of SUB account.
+    pub async fn get_sub_deposit_address(
+        &self,
+        params: GetSubDepositAddressParams,
+    ) -> Result<Response<SubDepositAddress>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::AssetDepositQuerySubMemberAddress
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Coin Info
+    /// Query coin information, including chain information, withdraw and deposit status.
+    pub async fn get_coin_info(
+        &self,
+        params: GetCoinInfoParams,
+    ) -> Result<Response<CoinsInfo>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!("{}{}?{query}", self.base_url, Path::AssetCoinQueryInfo);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Withdrawal Records
+    /// Query withdrawal records.
+    pub async fn get_withdrawal_records(
+        &self,
+        params: GetWithdrawalRecordsParams,
+    ) -> Result<Response<WithdrawalRecords>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::AssetWithdrawQueryRecord
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Withdraw
+    /// Withdraw assets from your Bybit account.
+    /// The withdrawal is checked against the client's `WithdrawPolicy` first and rejected without a request when it does not pass.
+    #[cfg(feature = "withdraw")]
+    pub async fn create_withdrawal(
+        &self,
+        params: CreateWithdrawalParams,
+    ) -> Result<Response<WithdrawalId>, Error> {
+        params.validate()?;
+
+        match self.withdraw_policy.as_ref() {
+            Some(policy) => policy.check(&params)?,
+            None => {
+                return Err(Error::WithdrawalRejected(String::from(
+                    "no withdraw policy configured",
+                )));
+            }
+        }
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::AssetWithdrawCreate);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Cancel Withdrawal
+    /// Cancel the withdrawal.
+    pub async fn cancel_withdrawal(
+        &self,
+        params: CancelWithdrawalParams,
+    ) -> Result<Response<CancelWithdrawalResult>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::AssetWithdrawCancel);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Get Sub UID
+    /// Query the sub UIDs under a main UID and the ones that have universal transfer enabled. It returns up to 2000 sub accounts.
+    pub async fn get_sub_member_transfer_list(
+        &self,
+    ) -> Result<Response<SubMemberTransferList>, Error> {
+        let url = format!("{}{}", self.base_url, Path::AssetTransferQuerySubMemberList);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, "").await?;
+        Ok(response)
+    }
+
+    /// Enable Universal Transfer for Sub UID
+    /// Transfer between sub-sub or main-sub.
+    pub async fn enable_sub_member_transfers(
+        &self,
+        params: EnableSubMemberTransferParams,
+    ) -> Result<Response<EmptyResult>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!(
+            "{}{}",
+            self.base_url,
+            Path::AssetTransferSaveTransferSubMember
+        );
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+}
+
+// User.
+impl Client {
+    /// Create Sub UID
+    /// Create a new sub user id. Use master account's api key.
+    pub async fn create_sub_member(
+        &self,
+        params: CreateSubMemberParams,
+    ) -> Result<Response<SubMember>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::UserCreateSubMember);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Get Sub UID List (Limited)
+    /// Get at most 10k sub UID of master account. Use master user's api key only.
+    pub async fn get_sub_members(&self) -> Result<Response<SubMembers>, Error> {
+        let url = format!("{}{}", self.base_url, Path::UserQuerySubMembers);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, "").await?;
+        Ok(response)
+    }
+
+    /// Freeze Sub UID
+    /// Freeze Sub UID. Use master user's api key only.
+    pub async fn freeze_sub_member(
+        &self,
+        params: FreezeSubMemberParams,
+    ) -> Result<Response<EmptyResult>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::UserFrozenSubMember);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Create Sub UID API Key
+    /// To create new API key for those newly created sub UID. Use master user's api key only.
+    pub async fn create_sub_api_key(
+        &self,
+        params: CreateSubApiKeyParams,
+    ) -> Result<Response<SubApiKey>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::UserCreateSubApi);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Modify Sub API Key
+    /// Modify the settings of sub api key. Use the sub account api key pending to be modified to call the endpoint or use master account api key to manage its sub account api key.
+    pub async fn update_sub_api_key(
+        &self,
+        params: UpdateSubApiKeyParams,
+    ) -> Result<Response<SubApiKey>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::UserUpdateSubApi);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Delete Sub API Key
+    /// Delete the api key of sub account. Use the sub api key pending to be delete to call the endpoint or use the master api key to delete corresponding sub account api key.
+    pub async fn delete_sub_api_key(
+        &self,
+        params: DeleteSubApiKeyParams,
+    ) -> Result<Response<EmptyResult>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::UserDeleteSubApi);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+}
+
+// Leveraged token.
+impl Client {
+    /// Get Leveraged Token Info
+    /// Query leverage token information.
+    pub async fn get_leveraged_token_info(
+        &self,
+        params: GetLeveragedTokenInfoParams,
+    ) -> Result<Response<List<LeveragedTokenInfo>>, Error> {
+        params.validate()?;
+
+        let url = format!("{}{}", self.base_url, Path::SpotLeverTokenInfo);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url).query(&params);
+
+        let response = self.send(request).await?;
+        Ok(response)
+    }
+
+    /// Get Leveraged Token Market
+    /// Get leverage token market information.
+    pub async fn get_leveraged_token_market(
+        &self,
+        params: GetLeveragedTokenMarketParams,
+    ) -> Result<Response<LeveragedTokenMarket>, Error> {
+        params.validate()?;
+
+        let url = format!("{}{}", self.base_url, Path::SpotLeverTokenReference);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url).query(&params);
+
+        let response = self.send(request).await?;
+        Ok(response)
+    }
+
+    /// Purchase
+    /// Purchase leverage token.
+    pub async fn purchase_leveraged_token(
+        &self,
+        params: PurchaseLeveragedTokenParams,
+    ) -> Result<Response<LeveragedTokenPurchase>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::SpotLeverTokenPurchase);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Redeem
+    /// Redeem leverage token.
+    pub async fn redeem_leveraged_token(
+        &self,
+        params: RedeemLeveragedTokenParams,
+    ) -> Result<Response<LeveragedTokenRedemption>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::SpotLeverTokenRedeem);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Get Purchase/Redemption Records
+    /// Get purchase or redeem history.
+    pub async fn get_leveraged_token_orders(
+        &self,
+        params: GetLeveragedTokenOrdersParams,
+    ) -> Result<Response<List<LeveragedTokenOrder>>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::SpotLeverTokenOrderRecord
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+}
+
+// Spot margin trade.
+impl Client {
+    /// Get VIP Margin Data
+    /// This margin data is for Unified account in particular.
+    pub async fn get_vip_margin_data(
+        &self,
+        params: GetVipMarginDataParams,
+    ) -> Result<Response<VipMarginData>, Error> {
+        params.validate()?;
+
+        let url = format!("{}{}", self.base_url, Path::SpotMarginTradeData);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url).query(&params);
+
+        let response = self.send(request).await?;
+        Ok(response)
+    }
+
+    /// Toggle Margin Trade
+    /// Turn on / off spot margin trade.
+    pub async fn switch_spot_margin_mode(
+        &self,
+        params: SwitchSpotMarginModeParams,
+    ) -> Result<Response<SpotMarginMode>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::SpotMarginTradeSwitchMode);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Set Leverage
+    /// Set the user's maximum leverage in spot cross margin.
+    pub async fn set_spot_margin_leverage(
+        &self,
+        params: SetSpotMarginLeverageParams,
+    ) -> Result<Response<EmptyResult>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::SpotMarginTradeSetLeverage);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Set Pledge Token
+    /// Set whether a coin is used as collateral for spot margin trading.
+    pub async fn set_pledge_token(
+        &self,
+        params: SetPledgeTokenParams,
+    ) -> Result<Response<EmptyResult>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::SpotMarginTradeSetPledgeToken);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Get Status And Leverage
+    /// Query the Spot margin status and leverage.
+    pub async fn get_spot_margin_state(&self) -> Result<Response<SpotMarginState>, Error> {
+        let url = format!("{}{}", self.base_url, Path::SpotMarginTradeState);
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, "").await?;
+        Ok(response)
+    }
+}
+
+// Convert.
+impl Client {
+    /// Get Convert Coin List
+    /// You can query the coin list of convert from (to) in the specific account.
+    pub async fn query_convert_coins(
+        &self,
+        params: GetConvertCoinsParams,
+    ) -> Result<Response<ConvertCoins>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::AssetExchangeQueryCoinList
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Request a Quote
+    /// The quote is valid until `expiredTime`, check it with `ConvertQuote::is_valid` before confirming.
+    pub async fn request_quote(
+        &self,
+        params: RequestQuoteParams,
+    ) -> Result<Response<ConvertQuote>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::AssetExchangeQuoteApply);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Confirm a Quote
+    /// The exchange is async; please check the final status by calling `get_convert_status`.
+    pub async fn confirm_quote(
+        &self,
+        params: ConfirmQuoteParams,
+    ) -> Result<Response<ConfirmQuoteResult>, Error> {
+        params.validate()?;
+
+        let body = serde_json::to_string(&params)?;
+        let url = format!("{}{}", self.base_url, Path::AssetExchangeConvertExecute);
+
+        let client = &self.http;
+        let request = client
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.clone());
+
+        let response = self.send_signed(request, &body).await?;
+        Ok(response)
+    }
+
+    /// Get Convert Status
+    /// You can query the exchange result by sending quoteTxId.
+    pub async fn get_convert_status(
+        &self,
+        params: GetConvertStatusParams,
+    ) -> Result<Response<ConvertStatusResult>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::AssetExchangeConvertResultQuery
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Convert history
+    /// Returns all confirmed quotes.
+    pub async fn get_convert_history(
+        &self,
+        params: GetConvertHistoryParams,
+    ) -> Result<Response<List<Convert>>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::AssetExchangeQueryConvertHistory
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+
+    /// Get Coin Exchange Records
+    /// Query the coin exchange records.
+    pub async fn get_exchange_records(
+        &self,
+        params: GetExchangeRecordsParams,
+    ) -> Result<Response<ExchangeRecords>, Error> {
+        params.validate()?;
+
+        let query = serde_urlencoded::to_string(&params)?;
+        let url = format!(
+            "{}{}?{query}",
+            self.base_url,
+            Path::AssetExchangeOrderRecord
+        );
+
+        let client = &self.http;
+        let request = client.request(Method::GET, url);
+
+        let response = self.send_signed(request, &query).await?;
+        Ok(response)
+    }
+}
+
+fn modify_outcome<T>(response: Result<Response<T>, Error>) -> Result<ModifyOutcome, Error> {
+    match response {
+        Ok(_) => Ok(ModifyOutcome::Modified),
+        Err(err) if err.is_not_modified() => Ok(ModifyOutcome::NotModified),
+        Err(err) => Err(err),
+    }
+}
+
 async fn send<T>(request: RequestBuilder) -> Result<Response<T>, Error>
 where
     T: serde::de::DeserializeOwned,
 {
     let response = request.send().await?;
-    let headers = parse_headers(&response.headers());
+    let headers = parse_headers(response.headers());
     let json = response.text().await?;
     if !headers.is_ret_code_ok() {
         let msg: APIErrorResponse = deserialize_str(&json)?;
@@ -215,29 +1813,23 @@ where
 fn parse_headers(headers: &HeaderMap) -> Headers {
     let ret_code = headers
         .get(HEADER_RET_CODE)
-        .map(|h| h.to_str().unwrap_or_default().parse().ok())
-        .flatten();
+        .and_then(|h| h.to_str().unwrap_or_default().parse().ok());
     let trace_id = headers
         .get(HEADER_TRACE_ID)
-        .map(|h| h.to_str().map(|str| str.into()).ok())
-        .flatten();
+        .and_then(|h| h.to_str().map(|str| str.into()).ok());
     let time_now = headers
         .get(HEADER_TIME_NOW)
-        .map(|h| h.to_str().unwrap_or_default().parse().ok())
-        .flatten();
+        .and_then(|h| h.to_str().unwrap_or_default().parse().ok());
 
     let api_limit = headers
         .get(HEADER_X_BAPI_LIMIT)
-        .map(|h| h.to_str().unwrap_or_default().parse().ok())
-        .flatten();
+        .and_then(|h| h.to_str().unwrap_or_default().parse().ok());
     let api_limit_status = headers
         .get(HEADER_X_BAPI_LIMIT_STATUS)
-        .map(|h| h.to_str().unwrap_or_default().parse().ok())
-        .flatten();
+        .and_then(|h| h.to_str().unwrap_or_default().parse().ok());
     let api_limit_reset_timestamp = headers
         .get(HEADER_X_BAPI_LIMIT_RESET_TIMESTAMP)
-        .map(|h| h.to_str().unwrap_or_default().parse().ok())
-        .flatten();
+        .and_then(|h| h.to_str().unwrap_or_default().parse().ok());
 
     Headers {
         ret_code,
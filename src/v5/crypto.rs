@@ -1,11 +1,25 @@
+use super::OutgoingMessage;
 use hex;
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
-use std::fmt::{self, Display, Formatter};
-
-#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::{
+        Arc,
+        atomic::{AtomicI64, Ordering},
+    },
+};
+
+/// A string that is never printed, neither by `Display` nor by `Debug`.
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct SensitiveString(String);
 
+impl fmt::Debug for SensitiveString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "REDACTED")
+    }
+}
+
 impl Display for SensitiveString {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "REDACTED")
@@ -65,13 +79,43 @@ pub fn hmac_sha256(key: impl AsRef<[u8]>, message: impl AsRef<[u8]>) -> String {
     hex::encode(&mac)
 }
 
+/// Offset between the local clock and the Bybit server clock.
+/// Clones share the same offset, so one sync adjusts every signer holding it.
+#[derive(Debug, Clone, Default)]
+pub struct TimeSync {
+    /// Milliseconds. Server time minus local time.
+    offset: Arc<AtomicI64>,
+}
+
+impl TimeSync {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return milliseconds.
+    pub fn offset(&self) -> i64 {
+        self.offset.load(Ordering::Relaxed)
+    }
+
+    pub fn set_offset(&self, offset: i64) {
+        self.offset.store(offset, Ordering::Relaxed);
+    }
+
+    /// Adjust a local timestamp (ms) to the server clock.
+    pub fn adjust(&self, timestamp: u128) -> u128 {
+        timestamp.saturating_add_signed(self.offset() as i128)
+    }
+}
+
 type Timer = fn() -> u128;
+#[derive(Clone)]
 pub struct Signer {
     api_key: SensitiveString,
     api_secret: SensitiveString,
     /// Milliseconds.
     recv_window: u64,
     timer: Timer,
+    time_sync: TimeSync,
 }
 
 impl Signer {
@@ -87,12 +131,33 @@ impl Signer {
             api_secret,
             recv_window,
             timer: timer.unwrap_or(timestamp),
+            time_sync: TimeSync::default(),
+        }
+    }
+
+    /// Use the shared clock offset for the request timestamps.
+    pub fn with_time_sync(mut self, time_sync: TimeSync) -> Self {
+        self.time_sync = time_sync;
+        self
+    }
+
+    pub fn time_sync(&self) -> &TimeSync {
+        &self.time_sync
+    }
+
+    /// Authentication message of a private stream, valid for `ttl` milliseconds.
+    pub fn stream_auth(&self, req_id: Option<String>, ttl: u64) -> OutgoingMessage {
+        let expires = self.time_sync.adjust((self.timer)()) as i64 + ttl as i64;
+        let signature = hmac_sha256(self.api_secret.expose(), format!("GET/realtime{expires}"));
+        OutgoingMessage::Auth {
+            req_id,
+            args: (self.api_key.expose().to_string(), expires, signature),
         }
     }
 
     /// return: (signature, timestamp)
     pub fn sign(&self, s: &str) -> (String, String) {
-        let timestamp = (self.timer)().to_string();
+        let timestamp = self.time_sync.adjust((self.timer)()).to_string();
         let api_key = self.api_key.expose();
         let api_secret = &self.api_secret.expose();
         let message = format!("{timestamp}{}{}{s}", api_key, &self.recv_window);
@@ -104,7 +169,7 @@ impl Signer {
 }
 
 /// Return milliseconds.
-fn timestamp() -> u128 {
+pub(crate) fn timestamp() -> u128 {
     std::time::UNIX_EPOCH.elapsed().unwrap().as_millis()
 }
 
@@ -136,9 +201,42 @@ mod tests {
         let json = r#"{"category":"option"}"#;
         let expected = "1b9b318f05208c9113f2612b2a6d76ca29427e6d8148937c03d6505f8c00804c";
 
-        let (signature, timestamp) = signer.sign(&json);
+        let (signature, timestamp) = signer.sign(json);
 
         assert_eq!(signature, expected);
         assert_eq!(timestamp.len(), 13);
     }
+
+    #[test]
+    fn sign_with_time_sync() {
+        let api_key = SensitiveString("API_KEY".to_string());
+        let api_secret = SensitiveString("API_SECRET".to_string());
+        let time_sync = TimeSync::new();
+        let signer = Signer::new(api_key, api_secret, 5000, Some(|| 1658384314791))
+            .with_time_sync(time_sync.clone());
+
+        time_sync.set_offset(-1000);
+        let (_, timestamp) = signer.sign("");
+
+        assert_eq!(timestamp, "1658384313791");
+    }
+
+    #[test]
+    fn sign_stream_auth() {
+        let api_key = SensitiveString("API_KEY".to_string());
+        let api_secret = SensitiveString("API_SECRET".to_string());
+        let signer = Signer::new(api_key, api_secret, 5000, Some(|| 1662350390000));
+
+        let message = signer.stream_auth(None, 10000);
+
+        let OutgoingMessage::Auth { args, .. } = message else {
+            panic!("expected auth message");
+        };
+        assert_eq!(args.0, "API_KEY");
+        assert_eq!(args.1, 1662350400000);
+        assert_eq!(
+            args.2,
+            hmac_sha256("API_SECRET", "GET/realtime1662350400000")
+        );
+    }
 }
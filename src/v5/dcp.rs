@@ -0,0 +1,196 @@
+use std::time::Duration;
+
+use tokio::{
+    sync::mpsc::{Receiver, Sender, channel},
+    task::JoinHandle,
+    time::{sleep, timeout},
+};
+
+use super::{
+    Client, CommandMsg, DEFAULT_PING_INTERVAL, DcpFunction, DcpProduct, DcpStatusMsg, Error,
+    IncomingMessage, OutgoingMessage, SetDcpParams, Signer, Topic, stream,
+};
+
+/// Delay before reconnecting a lost private stream.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+/// How long to wait for the auth and subscribe replies.
+const ACK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Request of the private stream whose reply must be successful.
+#[derive(Debug, Clone, Copy)]
+enum Ack {
+    Auth,
+    Subscribe,
+}
+
+/// DCP status pushed by Bybit and the health of the guard's private stream.
+#[derive(Debug)]
+pub enum DcpEvent {
+    Status(DcpStatusMsg),
+    /// The private stream closed, the guard is reconnecting.
+    Disconnected,
+    /// The private stream is authenticated and subscribed again.
+    Reconnected,
+    /// A reconnect attempt failed, the guard retries after a delay.
+    ReconnectFailed(Error),
+}
+
+/// Keeps a private stream open so that Bybit cancels all open orders of the product
+/// once the process dies and the stream stays disconnected longer than the DCP window.
+/// Dropping the guard closes the stream.
+pub struct DcpGuard {
+    events: Receiver<DcpEvent>,
+    task: JoinHandle<()>,
+}
+
+impl DcpGuard {
+    /// Set the DCP window (sec), then connect to the private stream at `url`,
+    /// authenticate and subscribe to the DCP status of the function.
+    /// Fail if Bybit rejects the auth or the subscription, or does not reply in time.
+    /// A lost connection is reconnected in the background.
+    pub async fn start(
+        client: &Client,
+        url: &str,
+        function: DcpFunction,
+        time_window: u64,
+    ) -> anyhow::Result<Self> {
+        let Some(signer) = client.signer().cloned() else {
+            return Err(Error::Msg(String::from("api key and secret are required")).into());
+        };
+
+        let params = SetDcpParams::new(time_window).product(DcpProduct::from(&function));
+        client.set_dcp(params).await?;
+
+        let topic = Topic::Dcp(function).to_string();
+        let (sender, receiver) = connect(url, &signer, &topic).await?;
+
+        let (events_tx, events) = channel(16);
+        let url = url.to_string();
+        let task = tokio::spawn(async move {
+            let mut connection = Some((sender, receiver));
+            loop {
+                if let Some((sender, receiver)) = connection.take() {
+                    let listening = forward(receiver, &events_tx).await;
+                    // Dropping the sender closes the old connection and stops its tasks.
+                    drop(sender);
+                    if !listening {
+                        break;
+                    }
+                    notify(&events_tx, DcpEvent::Disconnected);
+                }
+
+                sleep(RECONNECT_DELAY).await;
+                if events_tx.is_closed() {
+                    break;
+                }
+                match connect(&url, &signer, &topic).await {
+                    Ok(c) => {
+                        connection = Some(c);
+                        notify(&events_tx, DcpEvent::Reconnected);
+                    }
+                    Err(e) => notify(
+                        &events_tx,
+                        DcpEvent::ReconnectFailed(Error::Msg(e.to_string())),
+                    ),
+                }
+            }
+        });
+
+        Ok(Self { events, task })
+    }
+
+    /// Next DCP status or stream health event. Return None once the guard stopped.
+    /// Health events are dropped while 16 events are waiting, so a slow reader never holds up reconnecting.
+    pub async fn recv(&mut self) -> Option<DcpEvent> {
+        self.events.recv().await
+    }
+}
+
+impl Drop for DcpGuard {
+    fn drop(&mut self) {
+        // Stop reconnecting, the connection held by the task is closed with it.
+        self.task.abort();
+    }
+}
+
+async fn connect(
+    url: &str,
+    signer: &Signer,
+    topic: &str,
+) -> anyhow::Result<(Sender<OutgoingMessage>, Receiver<IncomingMessage>)> {
+    let (sender, mut receiver, _) = stream(url, DEFAULT_PING_INTERVAL).await?;
+
+    sender
+        .send(signer.stream_auth(Some(String::from("dcp-auth")), 10_000))
+        .await?;
+    wait_ack(&mut receiver, Ack::Auth).await?;
+
+    sender
+        .send(OutgoingMessage::Subscribe {
+            req_id: Some(String::from("dcp-subscribe")),
+            args: vec![topic.to_string()],
+        })
+        .await?;
+    wait_ack(&mut receiver, Ack::Subscribe).await?;
+
+    Ok((sender, receiver))
+}
+
+/// Wait for the reply to the request, skipping other messages.
+async fn wait_ack(receiver: &mut Receiver<IncomingMessage>, ack: Ack) -> Result<(), Error> {
+    let reply = timeout(ACK_TIMEOUT, async {
+        while let Some(message) = receiver.recv().await {
+            match (ack, message) {
+                (
+                    Ack::Auth,
+                    IncomingMessage::Command(CommandMsg::Auth {
+                        success, ret_msg, ..
+                    }),
+                ) => return Some((success, ret_msg)),
+                (
+                    Ack::Subscribe,
+                    IncomingMessage::Command(CommandMsg::Subscribe {
+                        success, ret_msg, ..
+                    }),
+                ) => return Some((success == Some(true), ret_msg)),
+                _ => {}
+            }
+        }
+        None
+    })
+    .await;
+
+    match reply {
+        Ok(Some((true, _))) => Ok(()),
+        Ok(Some((false, ret_msg))) => Err(Error::Msg(format!(
+            "DCP private stream {ack:?} failed with: {}",
+            ret_msg.unwrap_or_default()
+        ))),
+        Ok(None) => Err(Error::Msg(format!(
+            "DCP private stream closed before the {ack:?} reply"
+        ))),
+        Err(_) => Err(Error::Msg(format!(
+            "DCP private stream {ack:?} reply timed out"
+        ))),
+    }
+}
+
+/// Forward DCP status messages until the stream closes.
+/// Return false when nobody listens to the events anymore.
+async fn forward(mut receiver: Receiver<IncomingMessage>, events: &Sender<DcpEvent>) -> bool {
+    while let Some(message) = receiver.recv().await {
+        let IncomingMessage::Dcp(message) = message else {
+            continue;
+        };
+        for data in message.data {
+            if events.send(DcpEvent::Status(data)).await.is_err() {
+                return false;
+            }
+        }
+    }
+    !events.is_closed()
+}
+
+fn notify(events: &Sender<DcpEvent>, event: DcpEvent) {
+    let _ = events.try_send(event);
+}
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use serde_repr::*;
 use std::fmt;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub enum Locale {
     #[serde(rename = "de-DE")]
     DeDe,
@@ -48,7 +48,7 @@ pub enum Locale {
     FilPh,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub enum AnnouncementType {
     #[serde(rename = "new_crypto")]
     NewCrypto,
@@ -349,6 +349,15 @@ pub enum PositionIdx {
     Sell = 2,
 }
 
+#[derive(Serialize_repr, Deserialize_repr, Debug, PartialEq)]
+#[repr(u8)]
+pub enum PositionMode {
+    /// 0: Merged Single (one-way mode)
+    MergedSingle = 0,
+    /// 3: Both Sides (hedge mode)
+    BothSides = 3,
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub enum PositionStatus {
     Normal,
@@ -461,7 +470,7 @@ pub enum RejectReason {
     EcReachMarketPriceLimit,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub enum AccountType {
     /// Inverse Derivatives Account | Derivatives Account
     CONTRACT,
@@ -492,23 +501,63 @@ pub enum TransferStatus {
     FAILED,
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize_repr, Deserialize_repr, Debug, PartialEq)]
+#[repr(u16)]
 pub enum DepositStatus {
-    #[serde(rename = "0")]
-    Unknown,
-    #[serde(rename = "1")]
-    ToBeConfirmed,
-    #[serde(rename = "2")]
-    Processing,
+    Unknown = 0,
+    ToBeConfirmed = 1,
+    Processing = 2,
     /// (finalised status of a success deposit)
-    #[serde(rename = "3")]
-    Success,
-    #[serde(rename = "4")]
-    DepositFailed,
-    #[serde(rename = "10011")]
-    PendingToBeCreditedToFundingPool,
-    #[serde(rename = "10012")]
-    CreditedToFundingPoolSuccessfully,
+    Success = 3,
+    DepositFailed = 4,
+    PendingToBeCreditedToFundingPool = 10011,
+    CreditedToFundingPoolSuccessfully = 10012,
+}
+
+impl DepositStatus {
+    /// The deposit will not change its status anymore.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Self::Success | Self::DepositFailed | Self::CreditedToFundingPoolSuccessfully
+        )
+    }
+}
+
+#[derive(Serialize_repr, Deserialize_repr, Debug, PartialEq, Clone)]
+#[repr(u8)]
+pub enum SubMemberType {
+    Normal = 1,
+    /// Custodial sub account
+    Custodial = 6,
+}
+
+#[derive(Serialize_repr, Deserialize_repr, Debug, PartialEq, Clone)]
+#[repr(u8)]
+pub enum SubMemberStatus {
+    Normal = 1,
+    LoginBanned = 2,
+    Frozen = 4,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub enum WithdrawAccountType {
+    SPOT,
+    /// Funding Account
+    FUND,
+    /// Unified Trading Account
+    UTA,
+    /// Use the Funding Account first, then the Unified Trading Account
+    #[serde(rename = "FUND,UTA")]
+    FundUta,
+}
+
+#[derive(Serialize_repr, Deserialize_repr, Debug, PartialEq, Clone)]
+#[repr(u8)]
+pub enum WithdrawType {
+    OnChain = 0,
+    OffChain = 1,
+    All = 2,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -601,6 +650,16 @@ pub enum DataRecordingPeriod {
     Day4,
 }
 
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum SystemState {
+    #[serde(rename = "scheduled")]
+    Scheduled,
+    #[serde(rename = "ongoing")]
+    Ongoing,
+    #[serde(rename = "completed")]
+    Completed,
+}
+
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub enum ContractType {
     InversePerpetual,
@@ -818,22 +877,45 @@ impl Type {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize_repr, Deserialize_repr, Debug, PartialEq)]
+#[repr(u8)]
 pub enum UnifiedMarginStatus {
-    #[serde(rename = "1")]
-    ClassicAccount,
+    ClassicAccount = 1,
     /// 1.0
-    #[serde(rename = "3")]
-    UnifiedTradingAccount1,
+    UnifiedTradingAccount1 = 3,
     /// 1.0 (pro version)
-    #[serde(rename = "4")]
-    UnifiedTradingAccount1Pro,
+    UnifiedTradingAccount1Pro = 4,
     /// 2.0
-    #[serde(rename = "5")]
-    UnifiedTradingAccount2,
+    UnifiedTradingAccount2 = 5,
     /// 2.0 (pro version)
-    #[serde(rename = "6")]
-    UnifiedTradingAccount2Pro,
+    UnifiedTradingAccount2Pro = 6,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarginMode {
+    IsolatedMargin,
+    RegularMargin,
+    PortfolioMargin,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum UnifiedUpdateStatus {
+    /// Upgrade failed, see unifiedUpdateMsg for the reason
+    FAIL,
+    /// Upgrading
+    PROCESS,
+    /// Upgraded successfully
+    SUCCESS,
+}
+
+#[derive(Serialize_repr, Deserialize_repr, Debug, PartialEq)]
+#[repr(u8)]
+pub enum DemoAdjustType {
+    /// 0: add demo funds
+    Add = 0,
+    /// 1: reduce demo funds
+    Reduce = 1,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -850,6 +932,23 @@ pub enum LtStatus {
     AdjustingPosition,
 }
 
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum LtOrderStatus {
+    #[serde(rename = "1")]
+    Completed,
+    #[serde(rename = "2")]
+    InProgress,
+    #[serde(rename = "3")]
+    Failed,
+}
+
+#[derive(Serialize_repr, Deserialize_repr, Debug, PartialEq)]
+#[repr(u8)]
+pub enum LtOrderType {
+    Purchase = 1,
+    Redemption = 2,
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub enum ConvertAccountType {
     /// Unified Trading Account
@@ -869,6 +968,24 @@ pub enum ConvertAccountType {
     Contract,
 }
 
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ConvertStatus {
+    Init,
+    Processing,
+    Success,
+    Failure,
+}
+
+#[derive(Serialize_repr, Deserialize_repr, Debug, PartialEq)]
+#[repr(u8)]
+pub enum ConvertSide {
+    /// The coin is converted from
+    From = 0,
+    /// The coin is converted to
+    To = 1,
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub enum VipLevel {
     #[serde(rename = "No VIP")]
@@ -1046,13 +1163,13 @@ impl fmt::Display for Topic {
             Self::Kline { symbol, interval } => format!("kline.{interval}.{symbol}"),
             Self::AllLiquidation(symbol) => format!("allLiquidation.{symbol}"),
             Self::Position(category) => format!("position.{category}"),
-            Self::PositionAllCategory => format!("position"),
+            Self::PositionAllCategory => "position".to_string(),
             Self::Execution(category) => format!("execution.{category}"),
-            Self::ExecutionAllCategory => format!("execution"),
+            Self::ExecutionAllCategory => "execution".to_string(),
             Self::FastExecution(category) => format!("execution.fast.{category}"),
-            Self::FastExecutionAllCategory => format!("execution.fast"),
+            Self::FastExecutionAllCategory => "execution.fast".to_string(),
             Self::Order(category) => format!("order.{category}"),
-            Self::OrderAllCategory => format!("order"),
+            Self::OrderAllCategory => "order".to_string(),
             Self::Wallet => "wallet".to_string(),
             Self::Greek => "greek".to_string(),
             Self::Dcp(function) => format!("dcp.{function}"),
@@ -1071,6 +1188,24 @@ pub enum DcpFunction {
     Spot,
 }
 
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DcpProduct {
+    Options,
+    Derivatives,
+    Spot,
+}
+
+impl From<&DcpFunction> for DcpProduct {
+    fn from(function: &DcpFunction) -> Self {
+        match function {
+            DcpFunction::Future => Self::Derivatives,
+            DcpFunction::Option => Self::Options,
+            DcpFunction::Spot => Self::Spot,
+        }
+    }
+}
+
 impl fmt::Display for DcpFunction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let value = match self {
@@ -1114,7 +1249,7 @@ mod tests {
 
     #[test]
     fn serialize_category() {
-        let cases = vec![
+        let cases = [
             (Category::Inverse, r#""inverse""#),
             (Category::Linear, r#""linear""#),
             (Category::Option, r#""option""#),
@@ -1128,7 +1263,7 @@ mod tests {
 
     #[test]
     fn deserialize_category() {
-        let cases = vec![
+        let cases = [
             (r#""inverse""#, Category::Inverse),
             (r#""linear""#, Category::Linear),
             (r#""option""#, Category::Option),
@@ -1142,7 +1277,7 @@ mod tests {
 
     #[test]
     fn serialize_interval() {
-        let cases = vec![
+        let cases = [
             (Interval::Minute1, r#""1""#),
             (Interval::Minute3, r#""3""#),
             (Interval::Minute5, r#""5""#),
@@ -1165,7 +1300,7 @@ mod tests {
 
     #[test]
     fn deserialize_interval() {
-        let cases = vec![
+        let cases = [
             (r#""1""#, Interval::Minute1),
             (r#""3""#, Interval::Minute3),
             (r#""5""#, Interval::Minute5),
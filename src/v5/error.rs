@@ -1,12 +1,48 @@
+/// Not modified. Please ensure the parameters you submitted are different (trading stop).
+pub const RET_CODE_NOT_MODIFIED: i64 = 34040;
+/// Position mode is not modified.
+pub const RET_CODE_POSITION_MODE_NOT_MODIFIED: i64 = 110025;
+/// Cross/isolated margin mode is not modified.
+pub const RET_CODE_MARGIN_MODE_NOT_MODIFIED: i64 = 110026;
+/// Set leverage not modified.
+pub const RET_CODE_LEVERAGE_NOT_MODIFIED: i64 = 110043;
+
 #[derive(Debug)]
 pub enum Error {
-    Api { code: i64, msg: String },
+    Api {
+        code: i64,
+        msg: String,
+    },
     Io(std::io::Error),
     Msg(String),
     Reqwest(reqwest::Error),
     SerdeJson(serde_json::Error),
     SerdeUrlEncoded(serde_urlencoded::ser::Error),
     SerdePathToError(serde_path_to_error::Error<serde_json::Error>),
+    /// The params break a Bybit rule and were never sent. `field` is the camelCase path of the param,
+    /// alternatives are separated with `|`.
+    InvalidParams {
+        field: String,
+        reason: String,
+    },
+    /// The withdrawal was stopped by the client-side withdraw policy and never sent.
+    WithdrawalRejected(String),
+}
+
+impl Error {
+    /// Whether Bybit rejected the request only because the requested setting is already in place.
+    pub fn is_not_modified(&self) -> bool {
+        matches!(
+            self,
+            Error::Api { code, .. } if matches!(
+                *code,
+                RET_CODE_NOT_MODIFIED
+                    | RET_CODE_POSITION_MODE_NOT_MODIFIED
+                    | RET_CODE_MARGIN_MODE_NOT_MODIFIED
+                    | RET_CODE_LEVERAGE_NOT_MODIFIED
+            )
+        )
+    }
 }
 
 impl std::fmt::Display for Error {
@@ -24,6 +60,10 @@ impl std::fmt::Display for Error {
                 error.path(),
                 error.inner()
             ),
+            Error::InvalidParams { field, reason } => {
+                write!(f, "invalid params: {field}: {reason}")
+            }
+            Error::WithdrawalRejected(reason) => write!(f, "withdrawal rejected: {reason}"),
         }
     }
 }
@@ -52,7 +92,7 @@ impl From<super::APIErrorResponse> for Error {
     fn from(resp: super::APIErrorResponse) -> Self {
         Self::Api {
             code: resp.ret_code,
-            msg: resp.ret_msg.into(),
+            msg: resp.ret_msg,
         }
     }
 }
@@ -80,3 +120,26 @@ impl From<serde_path_to_error::Error<serde_json::Error>> for Error {
         Error::SerdePathToError(err)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn not_modified_codes() {
+        let cases = [
+            (RET_CODE_NOT_MODIFIED, true),
+            (RET_CODE_POSITION_MODE_NOT_MODIFIED, true),
+            (RET_CODE_MARGIN_MODE_NOT_MODIFIED, true),
+            (RET_CODE_LEVERAGE_NOT_MODIFIED, true),
+            (10001, false),
+        ];
+        cases.iter().for_each(|(code, expected)| {
+            let error = Error::Api {
+                code: *code,
+                msg: String::default(),
+            };
+            assert_eq!(error.is_not_modified(), *expected);
+        });
+    }
+}
@@ -0,0 +1,285 @@
+use std::collections::HashMap;
+
+use rust_decimal::{Decimal, dec};
+
+use super::{Category, Client, Error, FeeRate, GetFeeRatesParams, Pair, Side};
+
+/// Whether an order adds liquidity to the orderbook (maker) or takes it (taker).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Liquidity {
+    Maker,
+    Taker,
+}
+
+/// Expected trading fee of an order.
+/// A negative amount is a rebate.
+#[derive(Debug, PartialEq)]
+pub struct Fee {
+    pub amount: Decimal,
+    /// The coin of the symbol the fee is charged in.
+    pub coin: Pair,
+}
+
+/// Option fees are capped at this share of the premium.
+const OPTION_FEE_CAP: Decimal = dec!(0.125);
+
+/// Cache of the account fee rates of one category, keyed by symbol (base coin for options).
+pub struct FeeSchedule {
+    category: Category,
+    rates: HashMap<String, FeeRate>,
+}
+
+impl FeeSchedule {
+    pub fn new(category: Category, rates: Vec<FeeRate>) -> Self {
+        let mut schedule = Self {
+            category,
+            rates: HashMap::new(),
+        };
+        schedule.update(rates);
+        schedule
+    }
+
+    /// Load the fee rates of all symbols of the category.
+    /// For options use `load_options`, Bybit requires a base coin for option fee rates.
+    pub async fn load(client: &Client, category: Category) -> Result<Self, Error> {
+        let params = GetFeeRatesParams::new(category.clone());
+        let response = client.get_fee_rates(params).await?;
+        Ok(Self::new(category, response.result.list))
+    }
+
+    /// Load the option fee rates of the base coins, e.g. `["BTC", "ETH", "SOL"]`.
+    pub async fn load_options<S: Into<String>>(
+        client: &Client,
+        base_coins: impl IntoIterator<Item = S>,
+    ) -> Result<Self, Error> {
+        let mut schedule = Self::new(Category::Option, vec![]);
+        for base_coin in base_coins {
+            let response = client
+                .get_fee_rates(GetFeeRatesParams::option(base_coin))
+                .await?;
+            schedule.update(response.result.list);
+        }
+        Ok(schedule)
+    }
+
+    /// Add or replace the cached rates of the given symbols, rates of other symbols are kept.
+    /// Option rates are keyed by base coin, the others by symbol.
+    pub fn update(&mut self, rates: Vec<FeeRate>) {
+        for rate in rates {
+            let key = match (&self.category, &rate.base_coin) {
+                (Category::Option, Some(base_coin)) => base_coin.clone(),
+                _ => rate.symbol.clone(),
+            };
+            self.rates.insert(key, rate);
+        }
+    }
+
+    pub fn category(&self) -> &Category {
+        &self.category
+    }
+
+    /// Fee rate of the symbol. Option rates are keyed by base coin, pass e.g. `BTC` instead of the symbol.
+    pub fn rate(&self, symbol: &str, liquidity: Liquidity) -> Option<Decimal> {
+        self.rates.get(symbol).map(|rate| match liquidity {
+            Liquidity::Maker => rate.maker_fee_rate,
+            Liquidity::Taker => rate.taker_fee_rate,
+        })
+    }
+
+    /// Expected fee of an order, `None` if the symbol has no cached rate.
+    /// Always `None` for options, their fee depends on the index price, see `expected_option_fee`.
+    ///
+    /// - linear: `qty * price * rate` in the quote (settle) coin
+    /// - inverse: `qty / price * rate` in the base coin, qty is the number of contracts (USD)
+    /// - spot Buy: `qty * rate` in the base coin, spot Sell: `qty * price * rate` in the quote coin
+    pub fn expected_fee(
+        &self,
+        symbol: &str,
+        side: Side,
+        qty: Decimal,
+        price: Decimal,
+        liquidity: Liquidity,
+    ) -> Option<Fee> {
+        let rate = self.rate(symbol, liquidity)?;
+        let fee = match (&self.category, side) {
+            (Category::Option, _) => return None,
+            (Category::Linear, _) | (Category::Spot, Side::Sell) => Fee {
+                amount: qty * price * rate,
+                coin: Pair::Quote,
+            },
+            (Category::Inverse, _) => {
+                if price.is_zero() {
+                    return None;
+                }
+                Fee {
+                    amount: qty / price * rate,
+                    coin: Pair::Base,
+                }
+            }
+            (Category::Spot, Side::Buy) => Fee {
+                amount: qty * rate,
+                coin: Pair::Base,
+            },
+        };
+        Some(fee)
+    }
+
+    /// Expected fee of an option order in the settle coin, `None` if the base coin has no cached rate
+    /// or the schedule is not of the option category.
+    /// The fee is charged on the index price of the underlying and capped at 12.5% of the premium:
+    /// `min(qty * index_price * rate, qty * price * 0.125)`. A rebate is capped the same way.
+    pub fn expected_option_fee(
+        &self,
+        base_coin: &str,
+        qty: Decimal,
+        price: Decimal,
+        index_price: Decimal,
+        liquidity: Liquidity,
+    ) -> Option<Fee> {
+        if self.category != Category::Option {
+            return None;
+        }
+        let rate = self.rate(base_coin, liquidity)?;
+        let fee = qty * index_price * rate;
+        let cap = qty * price * OPTION_FEE_CAP;
+        let amount = if fee.is_sign_negative() {
+            fee.max(-cap)
+        } else {
+            fee.min(cap)
+        };
+        Some(Fee {
+            amount,
+            coin: Pair::Quote,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    fn rate(symbol: &str) -> FeeRate {
+        FeeRate {
+            symbol: String::from(symbol),
+            base_coin: None,
+            taker_fee_rate: dec!(0.00055),
+            maker_fee_rate: dec!(-0.0001),
+        }
+    }
+
+    #[test]
+    fn expected_fee_linear() {
+        let schedule = FeeSchedule::new(Category::Linear, vec![rate("BTCUSDT")]);
+
+        let taker = schedule.expected_fee(
+            "BTCUSDT",
+            Side::Buy,
+            dec!(0.5),
+            dec!(60000),
+            Liquidity::Taker,
+        );
+        let maker = schedule.expected_fee(
+            "BTCUSDT",
+            Side::Sell,
+            dec!(0.5),
+            dec!(60000),
+            Liquidity::Maker,
+        );
+        let unknown =
+            schedule.expected_fee("ETHUSDT", Side::Buy, dec!(1), dec!(3000), Liquidity::Taker);
+
+        assert_eq!(
+            taker,
+            Some(Fee {
+                amount: dec!(16.5),
+                coin: Pair::Quote
+            })
+        );
+        assert_eq!(
+            maker,
+            Some(Fee {
+                amount: dec!(-3),
+                coin: Pair::Quote
+            })
+        );
+        assert_eq!(unknown, None);
+    }
+
+    #[test]
+    fn expected_fee_spot() {
+        let schedule = FeeSchedule::new(Category::Spot, vec![rate("BTCUSDT")]);
+
+        let buy =
+            schedule.expected_fee("BTCUSDT", Side::Buy, dec!(2), dec!(60000), Liquidity::Taker);
+        let sell = schedule.expected_fee(
+            "BTCUSDT",
+            Side::Sell,
+            dec!(2),
+            dec!(60000),
+            Liquidity::Taker,
+        );
+
+        assert_eq!(
+            buy,
+            Some(Fee {
+                amount: dec!(0.0011),
+                coin: Pair::Base
+            })
+        );
+        assert_eq!(
+            sell,
+            Some(Fee {
+                amount: dec!(66),
+                coin: Pair::Quote
+            })
+        );
+    }
+
+    #[test]
+    fn expected_fee_inverse() {
+        let schedule = FeeSchedule::new(Category::Inverse, vec![rate("BTCUSD")]);
+
+        let fee = schedule.expected_fee(
+            "BTCUSD",
+            Side::Buy,
+            dec!(10000),
+            dec!(50000),
+            Liquidity::Taker,
+        );
+
+        assert_eq!(
+            fee,
+            Some(Fee {
+                amount: dec!(0.00011),
+                coin: Pair::Base
+            })
+        );
+    }
+
+    #[test]
+    fn expected_fee_option() {
+        let mut btc = rate("BTC-27JUN25-100000-C");
+        btc.base_coin = Some(String::from("BTC"));
+        btc.taker_fee_rate = dec!(0.0003);
+        let schedule = FeeSchedule::new(Category::Option, vec![btc]);
+
+        let uncapped = schedule.expected_option_fee(
+            "BTC",
+            dec!(1),
+            dec!(5000),
+            dec!(100000),
+            Liquidity::Taker,
+        );
+        let capped =
+            schedule.expected_option_fee("BTC", dec!(1), dec!(100), dec!(100000), Liquidity::Taker);
+
+        assert_eq!(uncapped.map(|fee| fee.amount), Some(dec!(30)));
+        assert_eq!(capped.map(|fee| fee.amount), Some(dec!(12.5)));
+        assert_eq!(
+            schedule.expected_fee("BTC", Side::Buy, dec!(1), dec!(5000), Liquidity::Taker),
+            None
+        );
+    }
+}
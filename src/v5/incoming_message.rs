@@ -5,10 +5,13 @@ use serde_aux::prelude::{
 };
 
 use super::{
-    CancelType, Category, CreateType, Interval, OcoTriggerBy, OrderStatus, OrderType, PlaceType,
-    PositionIdx, PositionStatus, RejectReason, Side, SlippageToleranceType, SmpType, StopOrderType,
-    TickDirection, TimeInForce, Timestamp, TpslMode, TradeMode, TriggerBy, TriggerDirection,
-    serde::{empty_string_as_none, int_to_bool, invalid_as_none, string_to_option_bool},
+    CancelType, Category, CreateType, DcpProduct, Interval, OcoTriggerBy, OrderStatus, OrderType,
+    PlaceType, PositionIdx, PositionStatus, RejectReason, Side, SlippageToleranceType, SmpType,
+    StopOrderType, TickDirection, TimeInForce, Timestamp, TpslMode, TradeMode, TriggerBy,
+    TriggerDirection,
+    serde::{
+        empty_string_as_none, int_to_bool, invalid_as_none, on_off_to_bool, string_to_option_bool,
+    },
 };
 
 #[derive(PartialEq, Deserialize, Debug)]
@@ -21,6 +24,7 @@ pub enum IncomingMessage {
     AllLiquidation(AllLiquidationMsg),
     Order(OrderMsg),
     Position(PositionMsg),
+    Dcp(DcpMsg),
 }
 
 #[derive(PartialEq, Deserialize, Debug)]
@@ -627,6 +631,29 @@ pub struct PositionUpdateMsg {
     pub seq: i64,
 }
 
+#[derive(PartialEq, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DcpMsg {
+    /// Topic name
+    pub topic: String,
+    /// Data created timestamp (ms)
+    pub creation_time: Timestamp,
+    pub data: Vec<DcpStatusMsg>,
+}
+
+#[derive(PartialEq, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DcpStatusMsg {
+    /// Product. OPTIONS, DERIVATIVES, SPOT
+    pub product: DcpProduct,
+    /// Disconnected-CancelAll function status. ON, OFF
+    #[serde(deserialize_with = "on_off_to_bool")]
+    pub dcp_status: bool,
+    /// The time window (sec)
+    #[serde(deserialize_with = "number")]
+    pub time_window: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::v5::serde::deserialize_str;
@@ -1073,4 +1100,31 @@ mod tests {
         });
         assert_eq!(message, expected);
     }
+
+    #[test]
+    fn deserialize_incoming_message_dcp() {
+        let json = r#"{
+            "id": "13e3f29a-1f4f-4e8f-9f5a-5d2b1b6b8d3c",
+            "topic": "dcp.future",
+            "creationTime": 1710232351256,
+            "data": [
+                {
+                    "product": "DERIVATIVES",
+                    "dcpStatus": "ON",
+                    "timeWindow": 10
+                }
+            ]
+        }"#;
+        let message: IncomingMessage = deserialize_str(json).unwrap();
+        let expected = IncomingMessage::Dcp(DcpMsg {
+            topic: String::from("dcp.future"),
+            creation_time: 1710232351256,
+            data: vec![DcpStatusMsg {
+                product: DcpProduct::Derivatives,
+                dcp_status: true,
+                time_window: 10,
+            }],
+        });
+        assert_eq!(message, expected);
+    }
 }
@@ -0,0 +1,392 @@
+use std::collections::HashMap;
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use super::{Category, Client, Error, GetInstrumentsInfoParams, InstrumentsInfo};
+
+/// Page size used to load instruments, the max allowed by Bybit.
+const INSTRUMENTS_PAGE_LIMIT: i64 = 1000;
+
+/// How to round a price to the tick size.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RoundingMode {
+    /// Towards zero, e.g. a buy price that must not exceed the given one.
+    Down,
+    /// Away from zero, e.g. a sell price that must not go below the given one.
+    Up,
+    /// To the nearest tick, midpoint away from zero.
+    Nearest,
+}
+
+impl From<RoundingMode> for RoundingStrategy {
+    fn from(mode: RoundingMode) -> Self {
+        match mode {
+            RoundingMode::Down => RoundingStrategy::ToZero,
+            RoundingMode::Up => RoundingStrategy::AwayFromZero,
+            RoundingMode::Nearest => RoundingStrategy::MidpointAwayFromZero,
+        }
+    }
+}
+
+/// Price and size rules of one instrument.
+#[derive(Debug, PartialEq, Clone)]
+pub struct InstrumentFilters {
+    /// The step to increase/reduce order price.
+    pub tick_size: Decimal,
+    /// The step to increase/reduce order quantity. Spot: base precision.
+    pub qty_step: Decimal,
+    pub min_order_qty: Decimal,
+    pub max_order_qty: Decimal,
+    /// Minimum order value. Spot: minimum order amount in the quote coin. Option: zero.
+    pub min_notional: Decimal,
+}
+
+/// Cache of the price and size rules of all instruments of one category, keyed by symbol.
+pub struct InstrumentRegistry {
+    category: Category,
+    /// Base coins of the option instruments to load.
+    base_coins: Vec<String>,
+    instruments: HashMap<String, InstrumentFilters>,
+}
+
+impl InstrumentRegistry {
+    pub fn new(category: Category) -> Self {
+        Self {
+            category,
+            base_coins: vec![],
+            instruments: HashMap::new(),
+        }
+    }
+
+    /// Load the option instruments of every base coin on `refresh`.
+    /// Without base coins Bybit returns BTC options only.
+    pub fn with_base_coins<S: Into<String>>(
+        mut self,
+        base_coins: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.base_coins = base_coins.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Load all instruments of the category, page by page.
+    /// For options use `load_options`, the category alone loads BTC options only.
+    pub async fn load(client: &Client, category: Category) -> Result<Self, Error> {
+        let mut registry = Self::new(category);
+        registry.refresh(client).await?;
+        Ok(registry)
+    }
+
+    /// Load the option instruments of the base coins, e.g. `["BTC", "ETH", "SOL"]`.
+    pub async fn load_options<S: Into<String>>(
+        client: &Client,
+        base_coins: impl IntoIterator<Item = S>,
+    ) -> Result<Self, Error> {
+        let mut registry = Self::new(Category::Option).with_base_coins(base_coins);
+        registry.refresh(client).await?;
+        Ok(registry)
+    }
+
+    /// Reload all instruments of the category (of every base coin for options).
+    /// The cache is replaced only when every page loaded.
+    pub async fn refresh(&mut self, client: &Client) -> Result<(), Error> {
+        let base_coins = match self.category {
+            Category::Option if !self.base_coins.is_empty() => {
+                self.base_coins.iter().cloned().map(Some).collect()
+            }
+            _ => vec![None],
+        };
+
+        let mut registry = Self::new(self.category.clone());
+        for base_coin in base_coins {
+            let mut cursor: Option<String> = None;
+            loop {
+                let mut params = GetInstrumentsInfoParams::new(self.category.clone())
+                    .limit(INSTRUMENTS_PAGE_LIMIT);
+                if let Some(base_coin) = base_coin.clone() {
+                    params = params.base_coin(base_coin);
+                }
+                if let Some(cursor) = cursor.take() {
+                    params = params.cursor(cursor);
+                }
+                let response = client.get_instruments_info(params).await?;
+
+                cursor = response.result.next_page_cursor().map(String::from);
+                registry.update(response.result);
+                if cursor.is_none() {
+                    break;
+                }
+            }
+        }
+
+        self.instruments = registry.instruments;
+        Ok(())
+    }
+
+    /// Add or replace the instruments of the page.
+    /// A page of another category is skipped.
+    pub fn update(&mut self, info: InstrumentsInfo) {
+        let instruments: Vec<(String, InstrumentFilters)> = match (&self.category, info) {
+            (Category::Inverse, InstrumentsInfo::Inverse { list, .. })
+            | (Category::Linear, InstrumentsInfo::Linear { list, .. }) => list
+                .into_iter()
+                .map(|i| {
+                    let filters = filters(
+                        i.price_filter.tick_size,
+                        i.lot_size_filter.qty_step,
+                        i.lot_size_filter.min_order_qty,
+                        i.lot_size_filter.max_order_qty,
+                        i.lot_size_filter.min_notional_value,
+                    );
+                    (i.symbol, filters)
+                })
+                .collect(),
+            // Options have no min notional.
+            (Category::Option, InstrumentsInfo::Option { list, .. }) => list
+                .into_iter()
+                .map(|i| {
+                    let filters = filters(
+                        i.price_filter.tick_size,
+                        i.lot_size_filter.qty_step,
+                        i.lot_size_filter.min_order_qty,
+                        i.lot_size_filter.max_order_qty,
+                        Decimal::ZERO,
+                    );
+                    (i.symbol, filters)
+                })
+                .collect(),
+            (Category::Spot, InstrumentsInfo::Spot { list, .. }) => list
+                .into_iter()
+                .map(|i| {
+                    let filters = filters(
+                        i.price_filter.tick_size,
+                        i.lot_size_filter.base_precision,
+                        i.lot_size_filter.min_order_qty,
+                        i.lot_size_filter.max_order_qty,
+                        i.lot_size_filter.min_order_amt,
+                    );
+                    (i.symbol, filters)
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        self.instruments.extend(instruments);
+    }
+
+    /// Add or replace the filters of the symbol.
+    pub fn insert(&mut self, symbol: impl Into<String>, filters: InstrumentFilters) {
+        self.instruments.insert(symbol.into(), filters);
+    }
+
+    pub fn category(&self) -> &Category {
+        &self.category
+    }
+
+    pub fn get(&self, symbol: &str) -> Option<&InstrumentFilters> {
+        self.instruments.get(symbol)
+    }
+
+    pub fn len(&self) -> usize {
+        self.instruments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instruments.is_empty()
+    }
+
+    /// Round the price to the tick size of the symbol, `None` if the symbol is unknown.
+    pub fn round_price(&self, symbol: &str, price: Decimal, mode: RoundingMode) -> Option<Decimal> {
+        let filters = self.instruments.get(symbol)?;
+        Some(round_to_step(price, filters.tick_size, mode.into()))
+    }
+
+    /// Round the quantity down to the quantity step of the symbol, `None` if the symbol is unknown.
+    pub fn round_qty(&self, symbol: &str, qty: Decimal) -> Option<Decimal> {
+        let filters = self.instruments.get(symbol)?;
+        Some(round_to_step(
+            qty,
+            filters.qty_step,
+            RoundingStrategy::ToZero,
+        ))
+    }
+
+    /// Order value in the unit of the min notional.
+    /// Inverse qty is the number of contracts (USD), the others are `qty * price`.
+    pub fn notional(&self, qty: Decimal, price: Decimal) -> Decimal {
+        match self.category {
+            Category::Inverse => qty,
+            Category::Linear | Category::Option | Category::Spot => qty * price,
+        }
+    }
+
+    /// Whether the order meets the min order quantity and min notional of the symbol,
+    /// `None` if the symbol is unknown.
+    pub fn meets_min_notional(&self, symbol: &str, qty: Decimal, price: Decimal) -> Option<bool> {
+        let filters = self.instruments.get(symbol)?;
+        Some(qty >= filters.min_order_qty && self.notional(qty, price) >= filters.min_notional)
+    }
+}
+
+fn filters(
+    tick_size: Decimal,
+    qty_step: Decimal,
+    min_order_qty: Decimal,
+    max_order_qty: Decimal,
+    min_notional: Decimal,
+) -> InstrumentFilters {
+    InstrumentFilters {
+        tick_size: tick_size.normalize(),
+        qty_step: qty_step.normalize(),
+        min_order_qty: min_order_qty.normalize(),
+        max_order_qty: max_order_qty.normalize(),
+        min_notional: min_notional.normalize(),
+    }
+}
+
+fn round_to_step(value: Decimal, step: Decimal, strategy: RoundingStrategy) -> Decimal {
+    if step.is_zero() {
+        return value;
+    }
+    ((value / step).round_dp_with_strategy(0, strategy) * step).normalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+    use crate::v5::serde::deserialize_str;
+
+    fn registry(category: Category) -> InstrumentRegistry {
+        let mut registry = InstrumentRegistry::new(category);
+        registry.insert(
+            "BTCUSDT",
+            filters(dec!(0.1), dec!(0.001), dec!(0.001), dec!(100), dec!(5)),
+        );
+        registry
+    }
+
+    fn option_page(symbol: &str, tick_size: &str, next_page_cursor: &str) -> InstrumentsInfo {
+        let json = format!(
+            r#"{{
+                "category": "option",
+                "nextPageCursor": "{next_page_cursor}",
+                "list": [
+                    {{
+                        "symbol": "{symbol}",
+                        "status": "Trading",
+                        "baseCoin": "ETH",
+                        "quoteCoin": "USD",
+                        "settleCoin": "USDC",
+                        "optionsType": "Put",
+                        "launchTime": "1672560000000",
+                        "deliveryTime": "1672732800000",
+                        "deliveryFeeRate": "0.00015",
+                        "priceFilter": {{
+                            "minPrice": "0.1",
+                            "maxPrice": "10000000",
+                            "tickSize": "{tick_size}"
+                        }},
+                        "lotSizeFilter": {{
+                            "maxOrderQty": "1500",
+                            "minOrderQty": "0.1",
+                            "qtyStep": "0.1"
+                        }},
+                        "displayName": ""
+                    }}
+                ]
+            }}"#
+        );
+        deserialize_str(&json).unwrap()
+    }
+
+    #[test]
+    fn update_pages() {
+        let mut registry = InstrumentRegistry::new(Category::Option);
+
+        let first = option_page("ETH-3JAN23-1250-P", "0.10", "page2");
+        assert_eq!(first.next_page_cursor(), Some("page2"));
+        registry.update(first);
+        let last = option_page("ETH-3JAN23-1300-C", "5", "");
+        assert_eq!(last.next_page_cursor(), None);
+        registry.update(last);
+
+        assert_eq!(registry.len(), 2);
+        assert_eq!(
+            registry.get("ETH-3JAN23-1250-P"),
+            Some(&InstrumentFilters {
+                tick_size: dec!(0.1),
+                qty_step: dec!(0.1),
+                min_order_qty: dec!(0.1),
+                max_order_qty: dec!(1500),
+                min_notional: dec!(0),
+            })
+        );
+        assert_eq!(
+            registry.round_price("ETH-3JAN23-1300-C", dec!(12.6), RoundingMode::Nearest),
+            Some(dec!(15))
+        );
+
+        // A page of another category is skipped.
+        let mut linear = InstrumentRegistry::new(Category::Linear);
+        linear.update(option_page("ETH-3JAN23-1250-P", "0.1", ""));
+        assert!(linear.is_empty());
+    }
+
+    #[test]
+    fn round_price() {
+        let registry = registry(Category::Linear);
+
+        let cases = [
+            (dec!(60000.17), RoundingMode::Down, dec!(60000.1)),
+            (dec!(60000.12), RoundingMode::Up, dec!(60000.2)),
+            (dec!(60000.15), RoundingMode::Nearest, dec!(60000.2)),
+            (dec!(60000.14), RoundingMode::Nearest, dec!(60000.1)),
+            (dec!(60000.1), RoundingMode::Up, dec!(60000.1)),
+        ];
+        cases.iter().for_each(|(price, mode, expected)| {
+            assert_eq!(
+                registry.round_price("BTCUSDT", *price, *mode),
+                Some(*expected)
+            );
+        });
+        assert_eq!(
+            registry.round_price("ETHUSDT", dec!(1), RoundingMode::Down),
+            None
+        );
+    }
+
+    #[test]
+    fn round_qty() {
+        let registry = registry(Category::Linear);
+
+        assert_eq!(
+            registry.round_qty("BTCUSDT", dec!(0.12345)),
+            Some(dec!(0.123))
+        );
+        assert_eq!(registry.round_qty("BTCUSDT", dec!(0.0009)), Some(dec!(0)));
+    }
+
+    #[test]
+    fn min_notional() {
+        let linear = registry(Category::Linear);
+        let inverse = registry(Category::Inverse);
+
+        assert_eq!(
+            linear.meets_min_notional("BTCUSDT", dec!(0.001), dec!(5000)),
+            Some(true)
+        );
+        assert_eq!(
+            linear.meets_min_notional("BTCUSDT", dec!(0.001), dec!(4999)),
+            Some(false)
+        );
+        assert_eq!(
+            inverse.meets_min_notional("BTCUSDT", dec!(4), dec!(60000)),
+            Some(false)
+        );
+        assert_eq!(
+            linear.meets_min_notional("ETHUSDT", dec!(1), dec!(3000)),
+            None
+        );
+    }
+}
@@ -1,21 +1,32 @@
 mod api;
 mod client;
 mod crypto;
+mod dcp;
 mod enums;
 mod error;
+mod fee;
 mod incoming_message;
+mod instrument;
 mod outgoing_message;
+mod pool;
 mod serde;
 mod stream;
 mod url;
+mod validate;
+#[cfg(feature = "withdraw")]
+mod withdraw;
 
 pub use api::*;
 pub use client::{Client, ClientConfig};
 pub use crypto::*;
+pub use dcp::{DcpEvent, DcpGuard};
 pub use enums::*;
 pub use error::*;
+pub use fee::{Fee, FeeSchedule, Liquidity};
 pub use incoming_message::*;
+pub use instrument::{InstrumentFilters, InstrumentRegistry, RoundingMode};
 pub use outgoing_message::*;
+pub use pool::{AccountPool, AccountResult, RateLimiter};
 pub use stream::{DEFAULT_PING_INTERVAL, stream};
 pub use url::{
     BASE_URL_API_DEMO_TRADING, BASE_URL_API_MAINNET_1, BASE_URL_API_MAINNET_2,
@@ -23,3 +34,6 @@ pub use url::{
     BASE_URL_API_TESTNET, BASE_URL_STREAM_DEMO_TRADING, BASE_URL_STREAM_MAINNET_1,
     BASE_URL_STREAM_MAINNET_2, BASE_URL_STREAM_MAINNET_3, BASE_URL_STREAM_TESTNET, Path,
 };
+pub use validate::Validate;
+#[cfg(feature = "withdraw")]
+pub use withdraw::WithdrawPolicy;
@@ -0,0 +1,323 @@
+use std::{collections::BTreeMap, future::Future, sync::Arc, time::Duration};
+
+use futures_util::future::join_all;
+use tokio::{
+    sync::Mutex,
+    time::{Instant, sleep_until},
+};
+
+use super::{
+    AccountType, AccountWallet, Category, Client, ClientConfig, Error, GetPositionInfoParams,
+    GetWalletBalanceParams, List, Position, TimeSync,
+};
+
+/// Spaces requests evenly so that at most `max_requests` are sent per `per`.
+/// Clones share the same budget.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    interval: Duration,
+    next: Arc<Mutex<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new(max_requests: u32, per: Duration) -> Self {
+        Self {
+            interval: per / max_requests.max(1),
+            next: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    pub fn per_second(max_requests: u32) -> Self {
+        Self::new(max_requests, Duration::from_secs(1))
+    }
+
+    /// Wait until the next request is allowed.
+    pub async fn acquire(&self) {
+        let mut next = self.next.lock().await;
+        let now = Instant::now();
+        if *next > now {
+            sleep_until(*next).await;
+        }
+        *next = (*next).max(now) + self.interval;
+    }
+}
+
+/// The result of a fan-out call for one account.
+#[derive(Debug)]
+pub struct AccountResult<T> {
+    /// Account name in the pool.
+    pub account: String,
+    pub result: Result<T, Error>,
+}
+
+/// Named clients (master and sub-accounts) sharing one HTTP connection pool and one clock offset.
+/// Every client gets its own rate limiter, because Bybit limits requests per UID.
+pub struct AccountPool {
+    http: reqwest::Client,
+    time_sync: TimeSync,
+    /// Requests per second of every client.
+    rate_limit: u32,
+    clients: BTreeMap<String, Client>,
+}
+
+impl AccountPool {
+    pub fn new(rate_limit: u32) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            time_sync: TimeSync::new(),
+            rate_limit,
+            clients: BTreeMap::new(),
+        }
+    }
+
+    /// Add or replace the client of the account.
+    pub fn insert(&mut self, account: impl Into<String>, cfg: ClientConfig) {
+        let client = Client::new(cfg)
+            .with_http_client(self.http.clone())
+            .with_time_sync(self.time_sync.clone())
+            .with_rate_limiter(RateLimiter::per_second(self.rate_limit));
+        self.clients.insert(account.into(), client);
+    }
+
+    pub fn remove(&mut self, account: &str) -> Option<Client> {
+        self.clients.remove(account)
+    }
+
+    pub fn get(&self, account: &str) -> Option<&Client> {
+        self.clients.get(account)
+    }
+
+    /// Account names in ascending order.
+    pub fn accounts(&self) -> impl Iterator<Item = &str> {
+        self.clients.keys().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    pub fn time_sync(&self) -> &TimeSync {
+        &self.time_sync
+    }
+
+    /// Sync the shared clock offset with the server time. Return the offset in milliseconds.
+    /// The server time is public, so any client of the pool can fetch it, with or without keys.
+    pub async fn sync_time(&self) -> Result<i64, Error> {
+        let Some(client) = self.clients.values().next() else {
+            return Err(Error::Msg(String::from("account pool is empty")));
+        };
+        let offset = client.server_time_offset().await?;
+        self.time_sync.set_offset(offset);
+        Ok(offset)
+    }
+
+    /// Run the call for every account concurrently.
+    pub async fn fan_out<'a, F, Fut, T>(&'a self, f: F) -> Vec<AccountResult<T>>
+    where
+        F: Fn(&'a Client) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let calls = self.clients.iter().map(|(account, client)| {
+            let call = f(client);
+            async move {
+                AccountResult {
+                    account: account.clone(),
+                    result: call.await,
+                }
+            }
+        });
+        join_all(calls).await
+    }
+
+    /// Positions of all accounts, following the cursor until the last page.
+    /// For linear, either symbol or settleCoin is required.
+    pub async fn get_positions(
+        &self,
+        category: Category,
+        symbol: Option<String>,
+        settle_coin: Option<String>,
+    ) -> Vec<AccountResult<Vec<Position>>> {
+        self.fan_out(|client| {
+            let category = category.clone();
+            let symbol = symbol.clone();
+            let settle_coin = settle_coin.clone();
+            async move {
+                let mut positions = vec![];
+                let mut cursor: Option<String> = None;
+                loop {
+                    let mut params = GetPositionInfoParams::new(category.clone()).limit(200);
+                    if let Some(symbol) = symbol.clone() {
+                        params = params.symbol(symbol);
+                    }
+                    if let Some(settle_coin) = settle_coin.clone() {
+                        params = params.settle_coin(settle_coin);
+                    }
+                    if let Some(cursor) = cursor.take() {
+                        params = params.cursor(cursor);
+                    }
+
+                    let response = client.get_position_info(params).await?;
+                    positions.extend(response.result.list);
+                    cursor = response.result.next_page_cursor;
+                    if cursor.is_none() {
+                        break;
+                    }
+                }
+                Ok(positions)
+            }
+        })
+        .await
+    }
+
+    /// Wallet balances of all accounts.
+    pub async fn get_wallet_balances(
+        &self,
+        account_type: AccountType,
+        coin: Option<String>,
+    ) -> Vec<AccountResult<List<AccountWallet>>> {
+        self.fan_out(|client| {
+            let mut params = GetWalletBalanceParams::new(account_type.clone());
+            if let Some(coin) = coin.clone() {
+                params = params.coin(coin);
+            }
+            async move {
+                let response = client.get_wallet_balance(params).await?;
+                Ok(response.result)
+            }
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+    use crate::v5::crypto::timestamp;
+
+    fn cfg(api_key: &str) -> ClientConfig {
+        ClientConfig {
+            base_url: String::from("http://localhost"),
+            api_key: Some(api_key.into()),
+            api_secret: Some("secret".into()),
+            recv_window: 5000,
+            referer: None,
+        }
+    }
+
+    #[test]
+    fn insert_accounts() {
+        let mut pool = AccountPool::new(10);
+        pool.insert("sub-1", cfg("KEY_1"));
+        pool.insert("master", cfg("KEY_0"));
+        pool.insert("sub-1", cfg("KEY_2"));
+
+        assert_eq!(pool.len(), 2);
+        assert_eq!(pool.accounts().collect::<Vec<_>>(), vec!["master", "sub-1"]);
+    }
+
+    #[tokio::test]
+    async fn fan_out_tags_results() {
+        let mut pool = AccountPool::new(10);
+        pool.insert("master", cfg("KEY_0"));
+        pool.insert("sub-1", cfg("KEY_1"));
+
+        let results = pool
+            .fan_out(|client| async move { Ok(client.base_url().len()) })
+            .await;
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].account, "master");
+        assert_eq!(results[1].account, "sub-1");
+        assert!(results.iter().all(|r| matches!(r.result, Ok(16))));
+    }
+
+    /// Serve `/v5/market/time` one hour ahead of the local clock.
+    async fn server_time_ahead() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buf = [0; 1024];
+                let _ = stream.read(&mut buf).await;
+                let time = timestamp() + 3_600_000;
+                let body = format!(
+                    r#"{{"retCode":0,"retMsg":"OK","result":{{"timeSecond":"{}","timeNano":"{}"}},"retExtInfo":{{}},"time":{time}}}"#,
+                    time / 1000,
+                    time * 1_000_000,
+                );
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nret_code: 0\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+        format!("http://{addr}")
+    }
+
+    #[tokio::test]
+    async fn sync_time_with_keyless_first_client() {
+        let base_url = server_time_ahead().await;
+        let mut pool = AccountPool::new(10);
+        pool.insert(
+            "public",
+            ClientConfig {
+                base_url: base_url.clone(),
+                api_key: None,
+                api_secret: None,
+                recv_window: 5000,
+                referer: None,
+            },
+        );
+        pool.insert(
+            "sub-1",
+            ClientConfig {
+                base_url,
+                ..cfg("KEY_1")
+            },
+        );
+
+        let offset = pool.sync_time().await.unwrap();
+
+        assert!((offset - 3_600_000).abs() < 1_000);
+        assert_eq!(pool.time_sync().offset(), offset);
+    }
+
+    #[tokio::test]
+    async fn signed_call_without_keys_fails() {
+        let mut pool = AccountPool::new(10);
+        pool.insert(
+            "public",
+            ClientConfig {
+                api_key: None,
+                api_secret: None,
+                ..cfg("KEY_0")
+            },
+        );
+
+        let results = pool.get_wallet_balances(AccountType::UNIFIED, None).await;
+
+        assert!(matches!(results[0].result, Err(Error::Msg(_))));
+    }
+
+    #[tokio::test]
+    async fn rate_limiter_spaces_requests() {
+        let rate_limiter = RateLimiter::new(10, Duration::from_millis(200));
+        let start = Instant::now();
+
+        for _ in 0..4 {
+            rate_limiter.acquire().await;
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(60));
+    }
+}
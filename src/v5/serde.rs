@@ -1,4 +1,4 @@
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serializer};
 
 pub fn invalid_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
 where
@@ -9,12 +9,13 @@ where
     Ok(res)
 }
 
-pub fn empty_string_as_none<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+pub fn empty_string_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
 where
     D: Deserializer<'de>,
+    T: From<String>,
 {
     let opt = Option::<String>::deserialize(deserializer)?;
-    Ok(opt.filter(|s| !s.trim().is_empty()))
+    Ok(opt.filter(|s| !s.trim().is_empty()).map(T::from))
 }
 
 pub fn int_to_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
@@ -63,6 +64,68 @@ where
     }
 }
 
+pub fn on_off_to_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    match s.as_str() {
+        "ON" => Ok(true),
+        "OFF" => Ok(false),
+        other => Err(serde::de::Error::custom(format!(
+            "invalid switch status: {}",
+            other
+        ))),
+    }
+}
+
+pub fn option_on_off_to_bool<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let opt = Option::<String>::deserialize(deserializer)?;
+    match opt.as_deref() {
+        Some("ON") => Ok(Some(true)),
+        Some("OFF") => Ok(Some(false)),
+        Some("") | None => Ok(None),
+        Some(other) => Err(serde::de::Error::custom(format!(
+            "invalid switch status: {}",
+            other
+        ))),
+    }
+}
+
+pub fn bool_to_int<S>(value: &bool, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u8(u8::from(*value))
+}
+
+pub fn bool_to_string<S>(value: &bool, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(if *value { "1" } else { "0" })
+}
+
+pub fn bool_to_on_off<S>(value: &bool, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(if *value { "ON" } else { "OFF" })
+}
+
+pub fn option_bool_to_int<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => serializer.serialize_some(&u8::from(*value)),
+        None => serializer.serialize_none(),
+    }
+}
+
 pub fn deserialize_str<'de, T>(
     json: &'de str,
 ) -> Result<T, serde_path_to_error::Error<serde_json::Error>>
@@ -15,6 +15,8 @@ use super::{IncomingMessage, OutgoingMessage};
 /// Default websocket ping interval (10seconds).
 pub const DEFAULT_PING_INTERVAL: Duration = Duration::from_secs(10);
 
+/// Connect to the websocket at `url` and ping it every `ping_interval`.
+/// Dropping the returned sender closes the connection and stops its tasks.
 pub async fn stream(
     url: &str,
     ping_interval: Duration,
@@ -29,11 +31,15 @@ pub async fn stream(
     let (stream, response) = connect_async(url).await?;
     let (mut sender, mut receiver) = stream.split();
 
-    let handshake = outgoing_tx.clone();
+    // A weak sender, so that pinging does not keep the connection open once the caller dropped its sender.
+    let handshake = outgoing_tx.downgrade();
     tokio::spawn(async move {
         let mut count = 0_u64;
         loop {
             sleep(ping_interval).await;
+            let Some(handshake) = handshake.upgrade() else {
+                break;
+            };
             count += 1;
             let id = format!("ping-{count}");
             let message = OutgoingMessage::Ping { req_id: Some(id) };
@@ -53,6 +59,7 @@ pub async fn stream(
                             Ok(message) => {
                                 if let Err(e) = incoming_tx.send(message).await {
                                     println!("Send IncomingMessage failed with: {e}");
+                                    break;
                                 }
                             }
                             Err(e) => {
@@ -100,6 +107,11 @@ pub async fn stream(
                 println!("Send OutgoingMessage failed with {e}");
             };
         }
+
+        // Every sender is dropped: close the connection, the server's close frame stops the reader.
+        if let Err(e) = sender.send(Message::Close(None)).await {
+            println!("Send Close failed with {e}");
+        };
     });
 
     Ok((outgoing_tx, incoming_rx, response))
@@ -68,6 +68,13 @@ pub enum Path {
     MarketInstrumentsInfo,
     MarketRiskLimit,
     MarketDeliveryPrice,
+    MarketAccountRatio,
+
+    // System status and maintenance
+    SystemStatus,
+
+    // Platform announcements
+    AnnouncementsIndex,
 
     // Order management
     OrderCreate,
@@ -80,6 +87,7 @@ pub enum Path {
     OrderAmendBatch,
     OrderCancelBatch,
     OrderSpotBorrowCheck,
+    OrderDisconnectedCancelAll,
 
     // Position management
     PositionList,
@@ -102,6 +110,7 @@ pub enum Path {
     AccountTransactionLog,
     AccountSetMarginMode,
     AccountSetMarginModeDemoApplyMoney,
+    AccountFeeRate,
 
     // Operations across multiple accounts – asset management, fund management, etc.
     AssetDeliveryRecord,
@@ -114,6 +123,7 @@ pub enum Path {
     AssetTransferQueryTransferCoinList,
     AssetTransferQuerySubMemberList,
     AssetTransferQueryAccountCoinBalance,
+    AssetTransferQueryAccountCoinsBalance,
     AssetTransferQueryAssetInfo,
     AssetDepositQueryAllowedList,
     AssetDepositQueryRecord,
@@ -125,6 +135,11 @@ pub enum Path {
     AssetDepositQueryAddress,
     AssetDepositQuerySubMemberAddress,
     AssetExchangeOrderRecord,
+    AssetExchangeQueryCoinList,
+    AssetExchangeQuoteApply,
+    AssetExchangeConvertExecute,
+    AssetExchangeConvertResultQuery,
+    AssetExchangeQueryConvertHistory,
 
     // Obtain quotes from Leveraged Tokens on Spot, and to exercise purchase and redeem functions
     SpotLeverTokenInfo,
@@ -137,6 +152,16 @@ pub enum Path {
     SpotMarginTradeSwitchMode,
     SpotMarginTradeSetLeverage,
     SpotMarginTradeSetPledgeToken,
+    SpotMarginTradeState,
+    SpotMarginTradeData,
+
+    // Manage sub-accounts and their API keys
+    UserCreateSubMember,
+    UserQuerySubMembers,
+    UserFrozenSubMember,
+    UserCreateSubApi,
+    UserUpdateSubApi,
+    UserDeleteSubApi,
 
     // Stream paths.
     PublicSpot,
@@ -165,6 +190,11 @@ impl fmt::Display for Path {
             Self::MarketInstrumentsInfo => "/v5/market/instruments-info",
             Self::MarketRiskLimit => "/v5/market/risk-limit",
             Self::MarketDeliveryPrice => "/v5/market/delivery-price",
+            Self::MarketAccountRatio => "/v5/market/account-ratio",
+
+            Self::SystemStatus => "/v5/system/status",
+
+            Self::AnnouncementsIndex => "/v5/announcements/index",
 
             Self::OrderCreate => "/v5/order/create",
             Self::OrderAmend => "/v5/order/amend",
@@ -176,6 +206,7 @@ impl fmt::Display for Path {
             Self::OrderAmendBatch => "/v5/order/amend-batch",
             Self::OrderCancelBatch => "/v5/order/cancel-batch",
             Self::OrderSpotBorrowCheck => "/v5/order/spot-borrow-check",
+            Self::OrderDisconnectedCancelAll => "/v5/order/disconnected-cancel-all",
 
             Self::PositionList => "/v5/position/list",
             Self::PositionSetLeverage => "/v5/position/set-leverage",
@@ -196,6 +227,7 @@ impl fmt::Display for Path {
             Self::AccountTransactionLog => "/v5/account/transaction-log",
             Self::AccountSetMarginMode => "/v5/account/set-margin-mode",
             Self::AccountSetMarginModeDemoApplyMoney => "/v5/account/demo-apply-money",
+            Self::AccountFeeRate => "/v5/account/fee-rate",
 
             Self::AssetDeliveryRecord => "/v5/asset/delivery-record",
             Self::AssetSettlementRecord => "/v5/asset/settlement-record",
@@ -217,6 +249,9 @@ impl fmt::Display for Path {
             Self::AssetTransferQueryAccountCoinBalance => {
                 "/v5/asset/transfer/query-account-coin-balance"
             }
+            Self::AssetTransferQueryAccountCoinsBalance => {
+                "/v5/asset/transfer/query-account-coins-balance"
+            }
             Self::AssetTransferQueryAssetInfo => "/v5/asset/transfer/query-asset-info",
             Self::AssetDepositQueryAllowedList => "/v5/asset/deposit/query-allowed-list",
             Self::AssetDepositQueryRecord => "/v5/asset/deposit/query-record",
@@ -228,6 +263,11 @@ impl fmt::Display for Path {
             Self::AssetDepositQueryAddress => "/v5/asset/deposit/query-address",
             Self::AssetDepositQuerySubMemberAddress => "/v5/asset/deposit/query-sub-member-address",
             Self::AssetExchangeOrderRecord => "/v5/asset/exchange/order-record",
+            Self::AssetExchangeQueryCoinList => "/v5/asset/exchange/query-coin-list",
+            Self::AssetExchangeQuoteApply => "/v5/asset/exchange/quote-apply",
+            Self::AssetExchangeConvertExecute => "/v5/asset/exchange/convert-execute",
+            Self::AssetExchangeConvertResultQuery => "/v5/asset/exchange/convert-result-query",
+            Self::AssetExchangeQueryConvertHistory => "/v5/asset/exchange/query-convert-history",
 
             Self::SpotLeverTokenInfo => "/v5/spot-lever-token/info",
             Self::SpotLeverTokenReference => "/v5/spot-lever-token/reference",
@@ -238,6 +278,15 @@ impl fmt::Display for Path {
             Self::SpotMarginTradeSwitchMode => "/v5/spot-margin-trade/switch-mode",
             Self::SpotMarginTradeSetLeverage => "/v5/spot-margin-trade/set-leverage",
             Self::SpotMarginTradeSetPledgeToken => "/v5/spot-margin-trade/set-pledge-token",
+            Self::SpotMarginTradeState => "/v5/spot-margin-trade/state",
+            Self::SpotMarginTradeData => "/v5/spot-margin-trade/data",
+
+            Self::UserCreateSubMember => "/v5/user/create-sub-member",
+            Self::UserQuerySubMembers => "/v5/user/query-sub-members",
+            Self::UserFrozenSubMember => "/v5/user/frozen-sub-member",
+            Self::UserCreateSubApi => "/v5/user/create-sub-api",
+            Self::UserUpdateSubApi => "/v5/user/update-sub-api",
+            Self::UserDeleteSubApi => "/v5/user/delete-sub-api",
 
             Self::PublicSpot => "/v5/public/spot",
             Self::PublicLinear => "/v5/public/linear",
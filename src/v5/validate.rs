@@ -0,0 +1,753 @@
+use std::fmt::Display;
+
+use rust_decimal::Decimal;
+
+use super::{
+    CancelWithdrawalParams, Category, ConfirmQuoteParams, CreateSubApiKeyParams,
+    CreateSubMemberParams, CreateWithdrawalParams, DeleteSubApiKeyParams,
+    EnableSubMemberTransferParams, Error, FreezeSubMemberParams, GetAllCoinsBalanceParams,
+    GetAllowedDepositCoinsParams, GetAnnouncementsParams, GetAssetInfoParams,
+    GetBorrowHistoryParams, GetClosedPnlParams, GetCoinBalanceParams, GetCoinInfoParams,
+    GetCollateralInfoParams, GetConvertCoinsParams, GetConvertHistoryParams,
+    GetConvertStatusParams, GetDeliveryPriceParams, GetDeliveryRecordsParams,
+    GetDepositAddressParams, GetDepositRecordsParams, GetExchangeRecordsParams,
+    GetExecutionsParams, GetFeeRatesParams, GetFundingRateHistoryParams,
+    GetHistoricalVolatilityParams, GetInstrumentsInfoParams, GetInsuranceParams, GetKLinesParams,
+    GetLeveragedTokenInfoParams, GetLeveragedTokenMarketParams, GetLeveragedTokenOrdersParams,
+    GetLongShortRatioParams, GetOpenClosedOrdersParams, GetOpenInterestParams,
+    GetPositionInfoParams, GetRiskLimitParams, GetSettlementRecordsParams,
+    GetSubDepositAddressParams, GetSubDepositRecordsParams, GetSystemStatusParams,
+    GetTickersParams, GetTradesParams, GetTransactionLogParams, GetTransferRecordsParams,
+    GetTransferableCoinsParams, GetVipMarginDataParams, GetWalletBalanceParams,
+    GetWithdrawalRecordsParams, InterTransferParams, OrderType, PurchaseLeveragedTokenParams,
+    RedeemLeveragedTokenParams, RequestDemoFundsParams, RequestQuoteParams, SetAutoAddMarginParams,
+    SetDcpParams, SetLeverageParams, SetMarginModeParams, SetPledgeTokenParams,
+    SetSpotMarginLeverageParams, SetTradingStopParams, SpotBorrowCheckParams, SwitchIsolatedParams,
+    SwitchPositionModeParams, SwitchSpotMarginModeParams, Timestamp, TpslMode,
+    UniversalTransferParams, UpdateSubApiKeyParams,
+};
+
+/// One day in milliseconds.
+const DAY: Timestamp = 24 * 60 * 60 * 1000;
+
+/// Client-side check of the request params against the Bybit rules.
+/// `Client` runs it before sending, so a request that Bybit would reject
+/// fails fast without spending a rate-limit token.
+pub trait Validate {
+    /// Params without documented rules are always valid.
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+fn invalid(field: &str, reason: impl Into<String>) -> Error {
+    Error::InvalidParams {
+        field: field.to_string(),
+        reason: reason.into(),
+    }
+}
+
+/// Check that the value, when passed, is within [min, max].
+fn range<T: PartialOrd + Display>(
+    field: &str,
+    value: Option<T>,
+    min: T,
+    max: T,
+) -> Result<(), Error> {
+    match value {
+        Some(value) if value < min || value > max => Err(invalid(
+            field,
+            format!("{value} is out of range [{min}, {max}]"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn positive(field: &str, value: Decimal) -> Result<(), Error> {
+    if value <= Decimal::ZERO {
+        return Err(invalid(field, format!("{value} must be greater than 0")));
+    }
+    Ok(())
+}
+
+fn not_negative(field: &str, value: Option<Decimal>) -> Result<(), Error> {
+    match value {
+        Some(value) if value.is_sign_negative() && !value.is_zero() => {
+            Err(invalid(field, format!("{value} cannot be less than 0")))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Check that the end is not before the start and, when `max_days` is set,
+/// that the range is at most `max_days` long.
+fn time_range(
+    fields: (&str, &str),
+    start: Option<Timestamp>,
+    end: Option<Timestamp>,
+    max_days: Option<u64>,
+) -> Result<(), Error> {
+    let (Some(start), Some(end)) = (start, end) else {
+        return Ok(());
+    };
+    let (start_field, end_field) = fields;
+    if end < start {
+        return Err(invalid(
+            end_field,
+            format!("cannot be before {start_field}"),
+        ));
+    }
+    match max_days {
+        Some(days) if end - start > days * DAY => Err(invalid(
+            end_field,
+            format!("{end_field} - {start_field} must be <= {days} days"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Check that at least one of the fields is passed.
+fn any_of(fields: &[(&str, bool)], category: &Category) -> Result<(), Error> {
+    if fields.iter().any(|(_, passed)| *passed) {
+        return Ok(());
+    }
+    let field = fields.iter().map(|(f, _)| *f).collect::<Vec<_>>().join("|");
+    Err(invalid(
+        &field,
+        format!("one of them is required for {category:?}"),
+    ))
+}
+
+impl Validate for CancelWithdrawalParams {}
+
+impl Validate for ConfirmQuoteParams {}
+
+impl Validate for CreateSubApiKeyParams {}
+
+impl Validate for CreateSubMemberParams {
+    fn validate(&self) -> Result<(), Error> {
+        let username = &self.username;
+        let len = username.chars().count();
+        if !(6..=16).contains(&len)
+            || !username.chars().all(|c| c.is_ascii_alphanumeric())
+            || !username.chars().any(|c| c.is_ascii_digit())
+            || !username.chars().any(|c| c.is_ascii_alphabetic())
+        {
+            return Err(invalid(
+                "username",
+                "must be 6-16 characters and include both numbers and letters",
+            ));
+        }
+        if let Some(password) = self.password.as_ref() {
+            let len = password.chars().count();
+            if !(8..=30).contains(&len)
+                || !password.chars().any(|c| c.is_ascii_digit())
+                || !password.chars().any(|c| c.is_ascii_uppercase())
+                || !password.chars().any(|c| c.is_ascii_lowercase())
+            {
+                return Err(invalid(
+                    "password",
+                    "must be 8-30 characters and include numbers, upper and lowercase letters",
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Validate for CreateWithdrawalParams {
+    fn validate(&self) -> Result<(), Error> {
+        positive("amount", self.amount)?;
+        if self.force_chain != Some(2) && self.chain.is_none() {
+            return Err(invalid("chain", "is required unless forceChain=2"));
+        }
+        Ok(())
+    }
+}
+
+impl Validate for DeleteSubApiKeyParams {}
+
+impl Validate for EnableSubMemberTransferParams {
+    fn validate(&self) -> Result<(), Error> {
+        match self.sub_member_ids.as_slice() {
+            [ids] if !ids.is_empty() => Ok(()),
+            _ => Err(invalid(
+                "subMemberIds",
+                "must have a single item, separate multiple UIDs by comma",
+            )),
+        }
+    }
+}
+
+impl Validate for FreezeSubMemberParams {}
+
+impl Validate for GetAllCoinsBalanceParams {}
+
+impl Validate for GetAllowedDepositCoinsParams {
+    fn validate(&self) -> Result<(), Error> {
+        range("limit", self.limit, 1, 35)
+    }
+}
+
+impl Validate for GetAnnouncementsParams {}
+
+impl Validate for GetAssetInfoParams {}
+
+impl Validate for GetBorrowHistoryParams {
+    fn validate(&self) -> Result<(), Error> {
+        time_range(
+            ("startTime", "endTime"),
+            self.start_time,
+            self.end_time,
+            Some(30),
+        )?;
+        range("limit", self.limit, 1, 50)
+    }
+}
+
+impl Validate for GetClosedPnlParams {
+    fn validate(&self) -> Result<(), Error> {
+        time_range(
+            ("startTime", "endTime"),
+            self.start_time,
+            self.end_time,
+            Some(7),
+        )?;
+        range("limit", self.limit, 1, 100)
+    }
+}
+
+impl Validate for GetCoinBalanceParams {}
+
+impl Validate for GetCoinInfoParams {}
+
+impl Validate for GetCollateralInfoParams {}
+
+impl Validate for GetConvertCoinsParams {}
+
+impl Validate for GetConvertHistoryParams {}
+
+impl Validate for GetConvertStatusParams {}
+
+impl Validate for GetDeliveryPriceParams {
+    fn validate(&self) -> Result<(), Error> {
+        range("limit", self.limit, 1, 200)
+    }
+}
+
+impl Validate for GetDeliveryRecordsParams {
+    fn validate(&self) -> Result<(), Error> {
+        time_range(
+            ("startTime", "endTime"),
+            self.start_time,
+            self.end_time,
+            Some(30),
+        )?;
+        range("limit", self.limit, 1, 50)
+    }
+}
+
+impl Validate for GetDepositAddressParams {}
+
+impl Validate for GetDepositRecordsParams {
+    fn validate(&self) -> Result<(), Error> {
+        time_range(
+            ("startTime", "endTime"),
+            self.start_time,
+            self.end_time,
+            Some(30),
+        )?;
+        range("limit", self.limit, 1, 50)
+    }
+}
+
+impl Validate for GetExchangeRecordsParams {
+    fn validate(&self) -> Result<(), Error> {
+        range("limit", self.limit, 1, 50)
+    }
+}
+
+impl Validate for GetExecutionsParams {
+    fn validate(&self) -> Result<(), Error> {
+        time_range(
+            ("startTime", "endTime"),
+            self.start_time,
+            self.end_time,
+            Some(7),
+        )?;
+        range("limit", self.limit, 1, 100)
+    }
+}
+
+impl Validate for GetFeeRatesParams {
+    fn validate(&self) -> Result<(), Error> {
+        if self.category == Category::Option && self.base_coin.is_none() {
+            return Err(invalid("baseCoin", "is required for Option"));
+        }
+        Ok(())
+    }
+}
+
+impl Validate for GetFundingRateHistoryParams {
+    fn validate(&self) -> Result<(), Error> {
+        if self.start_time.is_some() && self.end_time.is_none() {
+            return Err(invalid("endTime", "is required when startTime is passed"));
+        }
+        time_range(
+            ("startTime", "endTime"),
+            self.start_time,
+            self.end_time,
+            None,
+        )?;
+        range("limit", self.limit, 1, 200)
+    }
+}
+
+impl Validate for GetHistoricalVolatilityParams {
+    fn validate(&self) -> Result<(), Error> {
+        if self.start_time.is_some() != self.end_time.is_some() {
+            return Err(invalid(
+                "startTime|endTime",
+                "must be passed together or both are not passed",
+            ));
+        }
+        time_range(
+            ("startTime", "endTime"),
+            self.start_time,
+            self.end_time,
+            Some(30),
+        )?;
+        Ok(())
+    }
+}
+
+impl Validate for GetInstrumentsInfoParams {
+    fn validate(&self) -> Result<(), Error> {
+        range("limit", self.limit, 1, 1000)
+    }
+}
+
+impl Validate for GetInsuranceParams {}
+
+impl Validate for GetKLinesParams {
+    fn validate(&self) -> Result<(), Error> {
+        time_range(("start", "end"), self.start, self.end, None)?;
+        range("limit", self.limit, 1, 1000)
+    }
+}
+
+impl Validate for GetLeveragedTokenInfoParams {}
+
+impl Validate for GetLeveragedTokenMarketParams {}
+
+impl Validate for GetLeveragedTokenOrdersParams {
+    fn validate(&self) -> Result<(), Error> {
+        time_range(
+            ("startTime", "endTime"),
+            self.start_time,
+            self.end_time,
+            None,
+        )?;
+        range("limit", self.limit, 1, 500)
+    }
+}
+
+impl Validate for GetLongShortRatioParams {
+    fn validate(&self) -> Result<(), Error> {
+        time_range(
+            ("startTime", "endTime"),
+            self.start_time,
+            self.end_time,
+            None,
+        )?;
+        range("limit", self.limit, 1, 500)
+    }
+}
+
+impl Validate for GetOpenClosedOrdersParams {
+    fn validate(&self) -> Result<(), Error> {
+        if self.category == Category::Linear {
+            any_of(
+                &[
+                    ("symbol", self.symbol.is_some()),
+                    ("baseCoin", self.base_coin.is_some()),
+                    ("settleCoin", self.settle_coin.is_some()),
+                ],
+                &self.category,
+            )?;
+        }
+        range("limit", self.limit, 1, 50)
+    }
+}
+
+impl Validate for GetOpenInterestParams {
+    fn validate(&self) -> Result<(), Error> {
+        time_range(
+            ("startTime", "endTime"),
+            self.start_time,
+            self.end_time,
+            None,
+        )?;
+        range("limit", self.limit, 1, 200)
+    }
+}
+
+impl Validate for GetPositionInfoParams {
+    fn validate(&self) -> Result<(), Error> {
+        if self.category == Category::Linear {
+            any_of(
+                &[
+                    ("symbol", self.symbol.is_some()),
+                    ("settleCoin", self.settle_coin.is_some()),
+                ],
+                &self.category,
+            )?;
+        }
+        range("limit", self.limit, 1, 200)
+    }
+}
+
+impl Validate for GetRiskLimitParams {}
+
+impl Validate for GetSettlementRecordsParams {
+    fn validate(&self) -> Result<(), Error> {
+        time_range(
+            ("startTime", "endTime"),
+            self.start_time,
+            self.end_time,
+            Some(30),
+        )?;
+        range("limit", self.limit, 1, 50)
+    }
+}
+
+impl Validate for GetSubDepositAddressParams {}
+
+impl Validate for GetSubDepositRecordsParams {
+    fn validate(&self) -> Result<(), Error> {
+        time_range(
+            ("startTime", "endTime"),
+            self.start_time,
+            self.end_time,
+            None,
+        )?;
+        range("limit", self.limit, 1, 50)
+    }
+}
+
+impl Validate for GetSystemStatusParams {}
+
+impl Validate for GetTickersParams {
+    fn validate(&self) -> Result<(), Error> {
+        if self.category == Category::Option {
+            any_of(
+                &[
+                    ("symbol", self.symbol.is_some()),
+                    ("baseCoin", self.base_coin.is_some()),
+                ],
+                &self.category,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Validate for GetTradesParams {
+    fn validate(&self) -> Result<(), Error> {
+        if self.category != Category::Option {
+            any_of(&[("symbol", self.symbol.is_some())], &self.category)?;
+        }
+        let max = if self.category == Category::Spot {
+            60
+        } else {
+            1000
+        };
+        range("limit", self.limit, 1, max)
+    }
+}
+
+impl Validate for GetTransactionLogParams {
+    fn validate(&self) -> Result<(), Error> {
+        time_range(
+            ("startTime", "endTime"),
+            self.start_time,
+            self.end_time,
+            Some(7),
+        )?;
+        range("limit", self.limit, 1, 50)
+    }
+}
+
+impl Validate for GetTransferRecordsParams {
+    fn validate(&self) -> Result<(), Error> {
+        time_range(
+            ("startTime", "endTime"),
+            self.start_time,
+            self.end_time,
+            Some(7),
+        )?;
+        range("limit", self.limit, 1, 50)
+    }
+}
+
+impl Validate for GetTransferableCoinsParams {}
+
+impl Validate for GetVipMarginDataParams {}
+
+impl Validate for GetWalletBalanceParams {}
+
+impl Validate for GetWithdrawalRecordsParams {
+    fn validate(&self) -> Result<(), Error> {
+        time_range(
+            ("startTime", "endTime"),
+            self.start_time,
+            self.end_time,
+            Some(30),
+        )?;
+        range("limit", self.limit, 1, 50)
+    }
+}
+
+impl Validate for InterTransferParams {
+    fn validate(&self) -> Result<(), Error> {
+        positive("amount", self.amount)?;
+        if self.from_account_type == self.to_account_type {
+            return Err(invalid("toAccountType", "must differ from fromAccountType"));
+        }
+        Ok(())
+    }
+}
+
+impl Validate for PurchaseLeveragedTokenParams {
+    fn validate(&self) -> Result<(), Error> {
+        positive("amount", self.amount)
+    }
+}
+
+impl Validate for RedeemLeveragedTokenParams {
+    fn validate(&self) -> Result<(), Error> {
+        positive("quantity", self.quantity)
+    }
+}
+
+impl Validate for RequestDemoFundsParams {}
+
+impl Validate for RequestQuoteParams {
+    fn validate(&self) -> Result<(), Error> {
+        positive("requestAmount", self.request_amount)?;
+        if self.from_coin == self.to_coin {
+            return Err(invalid("toCoin", "must differ from fromCoin"));
+        }
+        Ok(())
+    }
+}
+
+impl Validate for SetAutoAddMarginParams {}
+
+impl Validate for SetDcpParams {
+    fn validate(&self) -> Result<(), Error> {
+        range("timeWindow", Some(self.time_window), 3, 300)
+    }
+}
+
+impl Validate for SetLeverageParams {
+    fn validate(&self) -> Result<(), Error> {
+        range(
+            "buyLeverage",
+            Some(self.buy_leverage),
+            Decimal::ONE,
+            Decimal::MAX,
+        )?;
+        range(
+            "sellLeverage",
+            Some(self.sell_leverage),
+            Decimal::ONE,
+            Decimal::MAX,
+        )
+    }
+}
+
+impl Validate for SetMarginModeParams {}
+
+impl Validate for SetPledgeTokenParams {}
+
+impl Validate for SetSpotMarginLeverageParams {
+    fn validate(&self) -> Result<(), Error> {
+        range("leverage", Some(self.leverage), Decimal::TWO, Decimal::TEN)
+    }
+}
+
+impl Validate for SetTradingStopParams {
+    fn validate(&self) -> Result<(), Error> {
+        not_negative("takeProfit", self.take_profit)?;
+        not_negative("stopLoss", self.stop_loss)?;
+        not_negative("trailingStop", self.trailing_stop)?;
+        if let Some(tp_size) = self.tp_size {
+            positive("tpSize", tp_size)?;
+        }
+        if let Some(sl_size) = self.sl_size {
+            positive("slSize", sl_size)?;
+        }
+        if let (Some(tp_size), Some(sl_size)) = (self.tp_size, self.sl_size)
+            && tp_size != sl_size
+        {
+            return Err(invalid("slSize", "must be equal to tpSize"));
+        }
+        if self.tpsl_mode == TpslMode::Full {
+            for (field, order_type) in [
+                ("tpOrderType", &self.tp_order_type),
+                ("slOrderType", &self.sl_order_type),
+            ] {
+                if matches!(order_type, Some(order_type) if *order_type != OrderType::Market) {
+                    return Err(invalid(field, "tpslMode=Full supports Market only"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Validate for SpotBorrowCheckParams {}
+
+impl Validate for SwitchIsolatedParams {
+    fn validate(&self) -> Result<(), Error> {
+        range(
+            "buyLeverage",
+            Some(self.buy_leverage),
+            Decimal::ONE,
+            Decimal::MAX,
+        )?;
+        if self.sell_leverage != self.buy_leverage {
+            return Err(invalid("sellLeverage", "must be equal to buyLeverage"));
+        }
+        Ok(())
+    }
+}
+
+impl Validate for SwitchPositionModeParams {}
+
+impl Validate for SwitchSpotMarginModeParams {}
+
+impl Validate for UniversalTransferParams {
+    fn validate(&self) -> Result<(), Error> {
+        positive("amount", self.amount)
+    }
+}
+
+impl Validate for UpdateSubApiKeyParams {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v5::SubMemberType;
+
+    fn field(result: Result<(), Error>) -> Option<String> {
+        match result {
+            Err(Error::InvalidParams { field, .. }) => Some(field),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn validate_limit() {
+        let params = GetOpenClosedOrdersParams::linear("BTCUSDT");
+        assert!(params.limit(50).validate().is_ok());
+
+        let params = GetOpenClosedOrdersParams::linear("BTCUSDT");
+        assert_eq!(
+            field(params.limit(51).validate()),
+            Some(String::from("limit"))
+        );
+
+        let params = GetExecutionsParams::new(Category::Spot);
+        assert_eq!(
+            field(params.limit(0).validate()),
+            Some(String::from("limit"))
+        );
+    }
+
+    #[test]
+    fn validate_required_symbol() {
+        assert_eq!(
+            field(GetTickersParams::new(Category::Option).validate()),
+            Some(String::from("symbol|baseCoin"))
+        );
+        assert!(
+            GetTickersParams::new(Category::Option)
+                .base_coin("BTC")
+                .validate()
+                .is_ok()
+        );
+        assert!(GetTickersParams::new(Category::Linear).validate().is_ok());
+
+        assert_eq!(
+            field(GetOpenClosedOrdersParams::new(Category::Linear).validate()),
+            Some(String::from("symbol|baseCoin|settleCoin"))
+        );
+        assert!(
+            GetOpenClosedOrdersParams::new(Category::Linear)
+                .settle_coin("USDT")
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            GetOpenClosedOrdersParams::new(Category::Spot)
+                .validate()
+                .is_ok()
+        );
+
+        assert_eq!(
+            field(GetFeeRatesParams::new(Category::Option).validate()),
+            Some(String::from("baseCoin"))
+        );
+        assert!(GetFeeRatesParams::option("ETH").validate().is_ok());
+        assert!(GetFeeRatesParams::new(Category::Linear).validate().is_ok());
+    }
+
+    #[test]
+    fn validate_time_range() {
+        let params = GetHistoricalVolatilityParams::new(Category::Option).start_time(1);
+        assert_eq!(
+            field(params.validate()),
+            Some(String::from("startTime|endTime"))
+        );
+
+        let params = GetFundingRateHistoryParams::new(Category::Linear, "BTCUSDT").start_time(1);
+        assert_eq!(field(params.validate()), Some(String::from("endTime")));
+
+        let params = GetClosedPnlParams::new(Category::Linear)
+            .start_time(0)
+            .end_time(8 * DAY);
+        assert_eq!(field(params.validate()), Some(String::from("endTime")));
+
+        let params = GetClosedPnlParams::new(Category::Linear)
+            .start_time(0)
+            .end_time(7 * DAY);
+        assert!(params.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_post_params() {
+        assert_eq!(
+            field(SetDcpParams::new(2).validate()),
+            Some(String::from("timeWindow"))
+        );
+        assert!(SetDcpParams::new(300).validate().is_ok());
+
+        let params = CreateWithdrawalParams::new("USDT", "0xabc", Decimal::ZERO);
+        assert_eq!(field(params.validate()), Some(String::from("amount")));
+        let params = CreateWithdrawalParams::new("USDT", "0xabc", Decimal::ONE);
+        assert_eq!(field(params.validate()), Some(String::from("chain")));
+        let params = CreateWithdrawalParams::new("USDT", "0xabc", Decimal::ONE).chain("ETH");
+        assert!(params.validate().is_ok());
+
+        let params = CreateSubMemberParams::new("subuser", SubMemberType::Normal);
+        assert_eq!(field(params.validate()), Some(String::from("username")));
+        let params =
+            CreateSubMemberParams::new("subuser1", SubMemberType::Normal).password("secret");
+        assert_eq!(field(params.validate()), Some(String::from("password")));
+        let params =
+            CreateSubMemberParams::new("subuser1", SubMemberType::Normal).password("Secret123");
+        assert!(params.validate().is_ok());
+    }
+}
@@ -0,0 +1,217 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use rust_decimal::Decimal;
+
+use super::{CreateWithdrawalParams, Error};
+
+/// Amounts that passed the check, oldest first, keyed by coin.
+type Withdrawn = HashMap<String, VecDeque<(Instant, Decimal)>>;
+
+/// Client-side rules every withdrawal must pass before it is sent to Bybit.
+/// Everything is denied by default: a withdrawal needs a chain, an allowlisted
+/// (coin, chain, address, tag) and an amount within the max amount configured for its coin.
+/// Clones share the withdrawn totals, so a policy cloned into several clients caps them together.
+#[derive(Debug, Default, Clone)]
+pub struct WithdrawPolicy {
+    /// Allowed (coin, chain, address, tag).
+    addresses: HashSet<(String, String, String, Option<String>)>,
+    /// Max amount of a single withdrawal, keyed by coin.
+    max_amounts: HashMap<String, Decimal>,
+    /// Max total amount withdrawn within a rolling window, keyed by coin.
+    max_totals: HashMap<String, (Decimal, Duration)>,
+    withdrawn: Arc<Mutex<Withdrawn>>,
+}
+
+impl WithdrawPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow withdrawals of the coin to the address on the chain, without a tag (memo).
+    pub fn allow_address(
+        self,
+        coin: impl Into<String>,
+        chain: impl Into<String>,
+        address: impl Into<String>,
+    ) -> Self {
+        self.allow(coin.into(), chain.into(), address.into(), None)
+    }
+
+    /// Allow withdrawals of the coin to the address and tag (memo) on the chain.
+    pub fn allow_address_with_tag(
+        self,
+        coin: impl Into<String>,
+        chain: impl Into<String>,
+        address: impl Into<String>,
+        tag: impl Into<String>,
+    ) -> Self {
+        self.allow(coin.into(), chain.into(), address.into(), Some(tag.into()))
+    }
+
+    fn allow(mut self, coin: String, chain: String, address: String, tag: Option<String>) -> Self {
+        self.addresses.insert((coin, chain, address, tag));
+        self
+    }
+
+    /// Limit the amount of a single withdrawal of the coin.
+    /// The limit applies per request, use `max_total` to cap repeated withdrawals.
+    pub fn max_amount(mut self, coin: impl Into<String>, amount: Decimal) -> Self {
+        self.max_amounts.insert(coin.into(), amount);
+        self
+    }
+
+    /// Limit the total amount of the coin withdrawn within the rolling `window`, e.g. 24 hours.
+    /// Every withdrawal that passes `check` counts, even if Bybit rejects it afterwards.
+    pub fn max_total(mut self, coin: impl Into<String>, amount: Decimal, window: Duration) -> Self {
+        self.max_totals.insert(coin.into(), (amount, window));
+        self
+    }
+
+    /// Check the withdrawal and, when it passes, count it towards the `max_total` of its coin.
+    pub fn check(&self, params: &CreateWithdrawalParams) -> Result<(), Error> {
+        self.check_at(params, Instant::now())
+    }
+
+    fn check_at(&self, params: &CreateWithdrawalParams, now: Instant) -> Result<(), Error> {
+        let coin = &params.coin;
+        let Some(chain) = params.chain.as_ref() else {
+            return Err(Error::WithdrawalRejected(String::from(
+                "chain is required by the withdraw policy",
+            )));
+        };
+        let key = (
+            coin.clone(),
+            chain.clone(),
+            params.address.clone(),
+            params.tag.clone(),
+        );
+        if !self.addresses.contains(&key) {
+            return Err(Error::WithdrawalRejected(format!(
+                "address {} (chain {chain}, tag {:?}) is not allowed for {coin}",
+                params.address, params.tag
+            )));
+        }
+
+        let Some(max_amount) = self.max_amounts.get(coin) else {
+            return Err(Error::WithdrawalRejected(format!(
+                "no max amount configured for {coin}"
+            )));
+        };
+        if params.amount <= Decimal::ZERO || params.amount > *max_amount {
+            return Err(Error::WithdrawalRejected(format!(
+                "amount {} {coin} is out of range (0, {max_amount}]",
+                params.amount
+            )));
+        }
+
+        let mut withdrawn = self.withdrawn.lock().unwrap_or_else(|e| e.into_inner());
+        let history = withdrawn.entry(coin.clone()).or_default();
+        if let Some((max_total, window)) = self.max_totals.get(coin) {
+            while let Some((at, _)) = history.front()
+                && now.duration_since(*at) >= *window
+            {
+                history.pop_front();
+            }
+            let total: Decimal = history.iter().map(|(_, amount)| amount).sum();
+            if total + params.amount > *max_total {
+                return Err(Error::WithdrawalRejected(format!(
+                    "total {} {coin} would exceed {max_total} within {window:?}",
+                    total + params.amount
+                )));
+            }
+            history.push_back((now, params.amount));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    fn params(coin: &str, address: &str, amount: Decimal) -> CreateWithdrawalParams {
+        CreateWithdrawalParams {
+            coin: String::from(coin),
+            chain: Some(String::from("ETH")),
+            address: String::from(address),
+            tag: None,
+            amount,
+            timestamp: 1670922217000,
+            force_chain: None,
+            account_type: None,
+            fee_type: None,
+            request_id: None,
+        }
+    }
+
+    #[test]
+    fn check_withdrawal() {
+        let policy = WithdrawPolicy::new()
+            .allow_address("USDT", "ETH", "0xabc")
+            .max_amount("USDT", dec!(100));
+
+        assert!(policy.check(&params("USDT", "0xabc", dec!(100))).is_ok());
+        assert!(policy.check(&params("USDT", "0xabc", dec!(100.1))).is_err());
+        assert!(policy.check(&params("USDT", "0xabc", dec!(0))).is_err());
+        assert!(policy.check(&params("USDT", "0xdef", dec!(1))).is_err());
+        assert!(policy.check(&params("USDC", "0xabc", dec!(1))).is_err());
+        assert!(
+            WithdrawPolicy::new()
+                .allow_address("USDT", "ETH", "0xabc")
+                .check(&params("USDT", "0xabc", dec!(1)))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn check_withdrawal_chain_and_tag() {
+        let policy = WithdrawPolicy::new()
+            .allow_address("USDT", "ETH", "0xabc")
+            .allow_address_with_tag("XRP", "XRP", "rAddress", "123")
+            .max_amount("USDT", dec!(100))
+            .max_amount("XRP", dec!(100));
+
+        let mut wrong_chain = params("USDT", "0xabc", dec!(1));
+        wrong_chain.chain = Some(String::from("TRX"));
+        assert!(policy.check(&wrong_chain).is_err());
+
+        let mut no_chain = params("USDT", "0xabc", dec!(1));
+        no_chain.chain = None;
+        assert!(policy.check(&no_chain).is_err());
+
+        let mut xrp = params("XRP", "rAddress", dec!(1));
+        xrp.chain = Some(String::from("XRP"));
+        assert!(policy.check(&xrp).is_err());
+        xrp.tag = Some(String::from("456"));
+        assert!(policy.check(&xrp).is_err());
+        xrp.tag = Some(String::from("123"));
+        assert!(policy.check(&xrp).is_ok());
+    }
+
+    #[test]
+    fn check_withdrawal_max_total() {
+        let policy = WithdrawPolicy::new()
+            .allow_address("USDT", "ETH", "0xabc")
+            .max_amount("USDT", dec!(100))
+            .max_total("USDT", dec!(150), Duration::from_secs(60));
+        let shared = policy.clone();
+        let now = Instant::now();
+
+        let params = params("USDT", "0xabc", dec!(60));
+        assert!(policy.check_at(&params, now).is_ok());
+        assert!(shared.check_at(&params, now).is_ok());
+        assert!(policy.check_at(&params, now).is_err());
+        assert!(
+            policy
+                .check_at(&params, now + Duration::from_secs(60))
+                .is_ok()
+        );
+    }
+}